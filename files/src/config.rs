@@ -0,0 +1,203 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use spacewars_game::{Campaign, ConfigError, GameConfig, KeyBindings};
+
+use crate::storage::APP_DIRECTORY;
+
+const CONFIG_FILES: [&str; 2] = ["config.toml", "config.ron"];
+const CAMPAIGN_FILE: &str = "campaign.ron";
+const BINDINGS_FILE: &str = "bindings.ron";
+
+fn config_directory() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join(APP_DIRECTORY))
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    let directory = config_directory()?;
+    CONFIG_FILES
+        .iter()
+        .map(|file| directory.join(file))
+        .find(|path| path.exists())
+}
+
+pub fn load_config() -> GameConfig {
+    let path = match config_path() {
+        Some(path) => path,
+        None => return GameConfig::default(),
+    };
+
+    match read_config(&path) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("Ignoring {:?}: {}", path, error);
+            GameConfig::default()
+        }
+    }
+}
+
+pub fn read_config(path: &Path) -> Result<GameConfig, ConfigError> {
+    let content =
+        fs::read_to_string(path).map_err(|error| ConfigError::Parse(error.to_string()))?;
+    let config = parse_config(path, &content)?;
+    config.validate()?;
+    Ok(config)
+}
+
+fn parse_config(path: &Path, content: &str) -> Result<GameConfig, ConfigError> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ron") => {
+            ron::from_str(content).map_err(|error| ConfigError::Parse(error.to_string()))
+        }
+        _ => toml::from_str(content).map_err(|error| ConfigError::Parse(error.to_string())),
+    }
+}
+
+pub fn load_campaign() -> Option<Campaign> {
+    read_campaign(&config_directory()?.join(CAMPAIGN_FILE))
+}
+
+/// A missing campaign is no error, as most players keep the built-in one.
+fn read_campaign(path: &Path) -> Option<Campaign> {
+    let content = fs::read_to_string(path).ok()?;
+
+    match Campaign::from_ron(&content) {
+        Ok(campaign) => Some(campaign),
+        Err(error) => {
+            eprintln!("Ignoring {:?}: {}", path, error);
+            None
+        }
+    }
+}
+
+/// `spacewars/bindings.ron` in the user config directory.
+pub fn bindings_path() -> Option<PathBuf> {
+    Some(config_directory()?.join(BINDINGS_FILE))
+}
+
+pub fn load_bindings() -> KeyBindings {
+    match bindings_path() {
+        Some(path) => read_bindings(&path),
+        None => KeyBindings::default(),
+    }
+}
+
+fn read_bindings(path: &Path) -> KeyBindings {
+    if !path.exists() {
+        return KeyBindings::default();
+    }
+
+    let bindings = fs::read_to_string(path)
+        .map_err(|error| ConfigError::Parse(error.to_string()))
+        .and_then(|content| KeyBindings::from_ron(&content));
+    match bindings {
+        Ok(bindings) => bindings,
+        Err(error) => {
+            eprintln!("Ignoring {:?}: {}", path, error);
+            KeyBindings::default()
+        }
+    }
+}
+
+pub fn save_bindings(bindings: &KeyBindings) -> io::Result<PathBuf> {
+    let path = bindings_path()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(&path, bindings.to_ron())?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spacewars_game::{Control, Game};
+
+    fn test_file(name: &str, content: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join("spacewars_files_tests");
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn missing_file(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join("spacewars_files_tests")
+            .join("missing")
+            .join(name)
+    }
+
+    #[test]
+    fn parse_config_should_read_ron_files_as_ron() {
+        // Arrange
+        let path = Path::new("config.ron");
+
+        // Act
+        let config = parse_config(path, "(star_count: 42)").unwrap();
+
+        // Assert
+        assert_eq!(config.star_count, 42);
+    }
+
+    #[test]
+    fn parse_config_should_read_other_files_as_toml() {
+        // Arrange
+        let path = Path::new("config.toml");
+
+        // Act
+        let toml = parse_config(path, "star_count = 42").unwrap();
+        let ron = parse_config(path, "(star_count: 42)");
+
+        // Assert
+        assert_eq!(toml.star_count, 42);
+        assert!(matches!(ron, Err(ConfigError::Parse(_))));
+    }
+
+    #[test]
+    fn read_campaign_should_ignore_missing_and_invalid_files() {
+        // Arrange
+        let missing = missing_file("campaign.ron");
+        let invalid = test_file("invalid_campaign.ron", "not a campaign");
+
+        // Act
+        let from_missing = read_campaign(&missing);
+        let from_invalid = read_campaign(&invalid);
+
+        // Assert
+        assert_eq!(from_missing, None);
+        assert_eq!(from_invalid, None);
+    }
+
+    #[test]
+    fn read_bindings_should_use_the_defaults_for_missing_and_invalid_files() {
+        // Arrange
+        let missing = missing_file("bindings.ron");
+        let invalid = test_file("invalid_bindings.ron", "not bindings");
+
+        // Act
+        let from_missing = read_bindings(&missing);
+        let from_invalid = read_bindings(&invalid);
+
+        // Assert
+        assert_eq!(from_missing, KeyBindings::default());
+        assert_eq!(from_invalid, KeyBindings::default());
+    }
+
+    #[test]
+    fn read_bindings_should_read_saved_bindings() {
+        // Arrange
+        let mut bindings = KeyBindings::default();
+        bindings.bind(Game::PLAYER_ONE, Control::Fire, "J").unwrap();
+        let path = test_file("bindings.ron", &bindings.to_ron());
+
+        // Act
+        let read = read_bindings(&path);
+
+        // Assert
+        assert_eq!(read, bindings);
+    }
+}
@@ -0,0 +1,8 @@
+pub use crate::config::{
+    bindings_path, config_path, load_bindings, load_campaign, load_config, read_config,
+    save_bindings,
+};
+pub use crate::storage::{high_score_path, FileStorage, APP_DIRECTORY};
+
+mod config;
+mod storage;
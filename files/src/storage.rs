@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::PathBuf;
+
+use spacewars_game::HighScoreStorage;
+
+pub const APP_DIRECTORY: &str = "spacewars";
+const HIGH_SCORE_FILE: &str = "high_scores.txt";
+
+pub fn high_score_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(APP_DIRECTORY)
+        .join(HIGH_SCORE_FILE)
+}
+
+pub struct FileStorage {
+    path: PathBuf,
+}
+
+impl FileStorage {
+    pub fn new(path: PathBuf) -> FileStorage {
+        FileStorage { path }
+    }
+}
+
+impl HighScoreStorage for FileStorage {
+    fn load(&self) -> Option<String> {
+        fs::read_to_string(&self.path).ok()
+    }
+
+    fn save(&mut self, content: &str) {
+        if let Some(directory) = self.path.parent() {
+            if fs::create_dir_all(directory).is_err() {
+                return;
+            }
+        }
+
+        let _ = fs::write(&self.path, content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_should_create_the_directory_and_load_should_read_it_back() {
+        // Arrange
+        let directory = std::env::temp_dir()
+            .join("spacewars_files_tests")
+            .join("storage");
+        let _ = fs::remove_dir_all(&directory);
+        let mut storage = FileStorage::new(directory.join(HIGH_SCORE_FILE));
+
+        // Act
+        storage.save("100;0;1;solo;AAA");
+
+        // Assert
+        assert_eq!(storage.load(), Some(String::from("100;0;1;solo;AAA")));
+    }
+
+    #[test]
+    fn load_should_return_nothing_without_a_file() {
+        // Arrange
+        let storage = FileStorage::new(PathBuf::from("missing/high_scores.txt"));
+
+        // Act
+        let content = storage.load();
+
+        // Assert
+        assert_eq!(content, None);
+    }
+}
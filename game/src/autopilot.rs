@@ -0,0 +1,133 @@
+use crate::game_objects::*;
+use crate::{Game, PlayerId, ReplayInput};
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Autopilot;
+
+impl Autopilot {
+    pub const ALIGN_TOLERANCE: f64 = 10.0;
+    pub const DODGE_WIDTH: f64 = 30.0;
+    pub const DODGE_HEIGHT: f64 = 120.0;
+
+    pub fn new() -> Autopilot {
+        Autopilot
+    }
+
+    pub fn act(&self, game: &mut Game, player: PlayerId) {
+        for input in self.inputs(game, player) {
+            input.apply(game);
+        }
+    }
+
+    /// The inputs a player would send to fly the hero this turn.
+    pub fn inputs(&self, game: &Game, player: PlayerId) -> Vec<ReplayInput> {
+        let (hero, facing) = match (game.hero_position(player), game.hero_direction(player)) {
+            (Some(position), Some(direction)) => (position, direction),
+            _ => return vec![],
+        };
+
+        if let Some(shot) = Autopilot::incoming_shot(game, player, hero, facing) {
+            let away = if shot.x >= hero.x {
+                Direction::LEFT
+            } else {
+                Direction::RIGHT
+            };
+            return vec![ReplayInput::Move(player, away), ReplayInput::Dash(player)];
+        }
+
+        match Autopilot::nearest_target(game, player, hero) {
+            Some(target) if (target.x - hero.x).abs() > Autopilot::ALIGN_TOLERANCE => {
+                let towards = if target.x > hero.x {
+                    Direction::RIGHT
+                } else {
+                    Direction::LEFT
+                };
+                vec![ReplayInput::Move(player, towards)]
+            }
+            Some(_) => vec![ReplayInput::Fire(player)],
+            None => vec![],
+        }
+    }
+
+    fn incoming_shot(
+        game: &Game,
+        player: PlayerId,
+        hero: Position,
+        facing: Direction,
+    ) -> Option<Position> {
+        let ahead = facing.vector().y;
+        game.shots_against(player).into_iter().find(|shot| {
+            let distance = (shot.y - hero.y) * ahead;
+            (shot.x - hero.x).abs() < Autopilot::DODGE_WIDTH
+                && distance > 0.0
+                && distance < Autopilot::DODGE_HEIGHT
+        })
+    }
+
+    fn nearest_target(game: &Game, player: PlayerId, hero: Position) -> Option<Position> {
+        game.target_positions(player).into_iter().min_by(|a, b| {
+            (a.x - hero.x)
+                .abs()
+                .partial_cmp(&(b.x - hero.x).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GameMode, MemoryStorage};
+
+    #[test]
+    fn act_should_play_a_game_through_the_player_input_api() {
+        // Arrange
+        let mut game = Game::new(800.0, 600.0, Box::new(MemoryStorage::default())).unwrap();
+        game.seed_random(3);
+        game.start(GameMode::Solo);
+        let autopilot = Autopilot::new();
+
+        // Act
+        for _ in 0..2000 {
+            autopilot.act(&mut game, Game::PLAYER_ONE);
+            game.next_turn();
+        }
+
+        // Assert
+        let stats = game.player_stats(Game::PLAYER_ONE).unwrap();
+        assert!(stats.shots_fired > 0);
+        assert!(stats.kills > 0);
+    }
+
+    #[test]
+    fn act_should_fire_at_the_other_player_from_the_top_of_a_versus_game() {
+        // Arrange
+        let mut game = Game::new(800.0, 600.0, Box::new(MemoryStorage::default())).unwrap();
+        game.seed_random(3);
+        game.start(GameMode::Versus);
+        let autopilot = Autopilot::new();
+
+        // Act
+        for _ in 0..400 {
+            autopilot.act(&mut game, Game::PLAYER_TWO);
+            game.next_turn();
+        }
+
+        // Assert
+        let stats = game.player_stats(Game::PLAYER_TWO).unwrap();
+        assert!(stats.shots_fired > 0);
+    }
+
+    #[test]
+    fn act_should_do_nothing_without_a_hero() {
+        // Arrange
+        let mut game = Game::new(800.0, 600.0, Box::new(MemoryStorage::default())).unwrap();
+        let autopilot = Autopilot::new();
+
+        // Act
+        autopilot.act(&mut game, Game::PLAYER_TWO);
+
+        // Assert
+        assert!(game.player_stats(Game::PLAYER_TWO).is_none());
+    }
+}
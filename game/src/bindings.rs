@@ -0,0 +1,652 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConfigError;
+use crate::game_objects::{Direction, ScreenSize, Vector};
+use crate::render::{Anchor, Layer, Primitive, RenderFrame};
+use crate::replay::ReplayInput;
+use crate::{Game, GameMode, PlayerId};
+
+/// A game action that keys are bound to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Control {
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    Fire,
+    Dash,
+    Confirm,
+    Pause,
+    StartCoop,
+    StartVersus,
+}
+
+impl Control {
+    pub const PLAYER_CONTROLS: [Control; 6] = [
+        Control::MoveLeft,
+        Control::MoveRight,
+        Control::MoveUp,
+        Control::MoveDown,
+        Control::Fire,
+        Control::Dash,
+    ];
+    pub const SHARED_CONTROLS: [Control; 4] = [
+        Control::Confirm,
+        Control::Pause,
+        Control::StartCoop,
+        Control::StartVersus,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match *self {
+            Control::MoveLeft => "Move left",
+            Control::MoveRight => "Move right",
+            Control::MoveUp => "Move up",
+            Control::MoveDown => "Move down",
+            Control::Fire => "Fire",
+            Control::Dash => "Dash",
+            Control::Confirm => "Confirm",
+            Control::Pause => "Pause",
+            Control::StartCoop => "Start co-op",
+            Control::StartVersus => "Start versus",
+        }
+    }
+
+    /// Shared controls belong to no player in particular.
+    pub fn is_shared(&self) -> bool {
+        Control::SHARED_CONTROLS.contains(self)
+    }
+
+    fn input(&self, player: PlayerId) -> ReplayInput {
+        match *self {
+            Control::MoveLeft => ReplayInput::Move(player, Direction::LEFT),
+            Control::MoveRight => ReplayInput::Move(player, Direction::RIGHT),
+            Control::MoveUp => ReplayInput::Move(player, Direction::UP),
+            Control::MoveDown => ReplayInput::Move(player, Direction::DOWN),
+            Control::Fire => ReplayInput::Fire(player),
+            Control::Dash => ReplayInput::Dash(player),
+            Control::Confirm => ReplayInput::Confirm,
+            Control::Pause => ReplayInput::Pause,
+            Control::StartCoop => ReplayInput::Start(GameMode::Coop),
+            Control::StartVersus => ReplayInput::Start(GameMode::Versus),
+        }
+    }
+}
+
+/// A key bound to a control. Keys use the same names on every platform:
+/// `Left`, `Right`, `Up`, `Down`, `Space`, `Enter`, `Shift`, `Escape`,
+/// `Backspace`, `F1` to `F12`, uppercase letters and digits, and the names
+/// of [`KeyBindings::character_key`] for punctuation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Binding {
+    pub key: String,
+    pub control: Control,
+    /// Ignored for shared controls.
+    #[serde(default)]
+    pub player: PlayerId,
+}
+
+const DEFAULT_BINDINGS: &[(&str, Control, PlayerId)] = &[
+    ("Left", Control::MoveLeft, Game::PLAYER_ONE),
+    ("Right", Control::MoveRight, Game::PLAYER_ONE),
+    ("Up", Control::MoveUp, Game::PLAYER_ONE),
+    ("Down", Control::MoveDown, Game::PLAYER_ONE),
+    ("Space", Control::Fire, Game::PLAYER_ONE),
+    ("Shift", Control::Dash, Game::PLAYER_ONE),
+    // Next to the right Shift, as terminals do not report Shift on its own.
+    ("Slash", Control::Dash, Game::PLAYER_ONE),
+    ("A", Control::MoveLeft, Game::PLAYER_TWO),
+    ("D", Control::MoveRight, Game::PLAYER_TWO),
+    ("W", Control::MoveUp, Game::PLAYER_TWO),
+    ("S", Control::MoveDown, Game::PLAYER_TWO),
+    // ZQSD for AZERTY keyboards.
+    ("Q", Control::MoveLeft, Game::PLAYER_TWO),
+    ("Z", Control::MoveUp, Game::PLAYER_TWO),
+    ("F", Control::Fire, Game::PLAYER_TWO),
+    ("G", Control::Dash, Game::PLAYER_TWO),
+    ("Enter", Control::Confirm, Game::PLAYER_ONE),
+    ("P", Control::Pause, Game::PLAYER_ONE),
+    ("2", Control::StartCoop, Game::PLAYER_ONE),
+    ("3", Control::StartVersus, Game::PLAYER_ONE),
+];
+
+/// Maps keys to controls, shared by every frontend so that they offer the
+/// same defaults and can read the same saved bindings.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    bindings: Vec<Binding>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> KeyBindings {
+        KeyBindings {
+            bindings: DEFAULT_BINDINGS
+                .iter()
+                .map(|&(key, control, player)| Binding {
+                    key: String::from(key),
+                    control,
+                    player,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Keys the frontends keep for themselves: Escape quits, F2 rebinds, F9
+    /// takes a screenshot, F10 records and F11 saves the replay.
+    pub const RESERVED_KEYS: [&'static str; 5] = ["Escape", "F2", "F9", "F10", "F11"];
+
+    pub fn from_ron(text: &str) -> Result<KeyBindings, ConfigError> {
+        let bindings: KeyBindings =
+            ron::from_str(text).map_err(|error| ConfigError::Parse(error.to_string()))?;
+        bindings.validate()?;
+        Ok(bindings)
+    }
+
+    /// Saved bindings are checked when loaded, as a reserved key in them
+    /// would never reach the game.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for binding in &self.bindings {
+            check_key(&binding.key)?;
+        }
+        Ok(())
+    }
+
+    /// Names a character key as the desktop frontend does, so that every
+    /// frontend reads and saves the same names: `a` is `A` and `,` is
+    /// `Comma`.
+    pub fn character_key(character: char) -> String {
+        let name = match character {
+            ' ' => "Space",
+            ',' => "Comma",
+            '.' => "Period",
+            '/' => "Slash",
+            '\\' => "Backslash",
+            ';' => "Semicolon",
+            '\'' => "Quote",
+            '`' => "Backquote",
+            '-' => "Minus",
+            '=' => "Equals",
+            '[' => "LeftBracket",
+            ']' => "RightBracket",
+            _ => return character.to_uppercase().to_string(),
+        };
+        String::from(name)
+    }
+
+    /// Whether a repeat of a held key counts as a press. Repeats steer the
+    /// hero, but would fire the reserved hotkeys and fill the rebinding
+    /// screen once per repeat.
+    pub fn accepts_repeat(key: &str, rebinding: bool) -> bool {
+        !rebinding && !KeyBindings::is_reserved(key)
+    }
+
+    pub fn is_reserved(key: &str) -> bool {
+        KeyBindings::RESERVED_KEYS
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(key))
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("Key bindings are always serializable")
+    }
+
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    pub fn keys(&self, player: PlayerId, control: Control) -> Vec<&str> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.control == control)
+            .filter(|binding| control.is_shared() || binding.player == player)
+            .map(|binding| binding.key.as_str())
+            .collect()
+    }
+
+    /// Makes `key` the only key of the control, taking it from any other
+    /// control it was bound to. Reserved keys never reach the bindings, so
+    /// they are refused.
+    pub fn bind(
+        &mut self,
+        player: PlayerId,
+        control: Control,
+        key: &str,
+    ) -> Result<(), ConfigError> {
+        check_key(key)?;
+
+        self.bindings.retain(|binding| {
+            let same_control =
+                binding.control == control && (control.is_shared() || binding.player == player);
+            !same_control && !binding.key.eq_ignore_ascii_case(key)
+        });
+        self.bindings.push(Binding {
+            key: String::from(key),
+            control,
+            player,
+        });
+        Ok(())
+    }
+
+    /// Whether the key is bound to a control. Other keys only send the "any
+    /// key" start.
+    pub fn is_bound(&self, key: &str) -> bool {
+        self.bindings
+            .iter()
+            .any(|binding| binding.key.eq_ignore_ascii_case(key))
+    }
+
+    /// The inputs a key press sends to a game with `player_count` players:
+    /// mode changes first, then the controls. Keys bound to no control send
+    /// the "any key" start instead. In games with fewer players, the keys of
+    /// the missing players drive player one, so that WASD also works in solo
+    /// games.
+    pub fn inputs(&self, key: &str, player_count: usize) -> Vec<ReplayInput> {
+        let controls: Vec<ReplayInput> = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.key.eq_ignore_ascii_case(key))
+            .map(|binding| {
+                let player = if binding.player < player_count {
+                    binding.player
+                } else {
+                    Game::PLAYER_ONE
+                };
+                binding.control.input(player)
+            })
+            .collect();
+        if controls.is_empty() {
+            return vec![ReplayInput::KeyPressed];
+        }
+
+        let (mut inputs, others): (Vec<ReplayInput>, Vec<ReplayInput>) = controls
+            .into_iter()
+            .partition(|input| matches!(input, ReplayInput::Start(_)));
+        inputs.extend(others);
+        inputs
+    }
+}
+
+fn check_key(key: &str) -> Result<(), ConfigError> {
+    if KeyBindings::is_reserved(key) {
+        return Err(ConfigError::Invalid {
+            field: "key",
+            reason: "is reserved by the frontends",
+        });
+    }
+    Ok(())
+}
+
+/// Walks through every control, asking for a new key for each.
+pub struct Rebinding {
+    bindings: KeyBindings,
+    step: usize,
+    refused: Option<String>,
+    cancelled: bool,
+}
+
+impl Rebinding {
+    /// Keeps the current keys of a control.
+    pub const SKIP_KEY: &'static str = "Backspace";
+    /// The key that opens the screen also leaves it, dropping the changes.
+    pub const CANCEL_KEY: &'static str = "F2";
+    pub const TEXT_COLOR: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
+    pub const HINT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+    pub const TITLE_TOP_PADDING: f64 = 120.0;
+    pub const CURRENT_TOP_PADDING: f64 = 50.0;
+    pub const HINT_TOP_PADDING: f64 = 150.0;
+
+    pub fn new(bindings: KeyBindings) -> Rebinding {
+        Rebinding {
+            bindings,
+            step: 0,
+            refused: None,
+            cancelled: false,
+        }
+    }
+
+    fn slots() -> Vec<(PlayerId, Control)> {
+        let player_slots = [Game::PLAYER_ONE, Game::PLAYER_TWO]
+            .into_iter()
+            .flat_map(|player| Control::PLAYER_CONTROLS.map(|control| (player, control)));
+        let shared_slots = Control::SHARED_CONTROLS.map(|control| (Game::PLAYER_ONE, control));
+
+        player_slots.chain(shared_slots).collect()
+    }
+
+    /// The control waiting for a key, or `None` once every control is done.
+    pub fn current(&self) -> Option<(PlayerId, Control)> {
+        Rebinding::slots().get(self.step).copied()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.current().is_none()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn bindings(&self) -> &KeyBindings {
+        &self.bindings
+    }
+
+    pub fn press(&mut self, key: &str) {
+        let Some((player, control)) = self.current() else {
+            return;
+        };
+
+        if key.eq_ignore_ascii_case(Rebinding::CANCEL_KEY) {
+            self.cancelled = true;
+            return;
+        }
+
+        self.refused = None;
+        let skipped = key.eq_ignore_ascii_case(Rebinding::SKIP_KEY);
+        if !skipped && self.bindings.bind(player, control, key).is_err() {
+            self.refused = Some(String::from(key));
+            return;
+        }
+        self.step += 1;
+    }
+
+    pub fn render_frame(&self, size: ScreenSize) -> RenderFrame {
+        let mut frame = RenderFrame::new(size);
+        let Some((player, control)) = self.current() else {
+            return frame;
+        };
+
+        let (width, height) = size;
+        let title = if control.is_shared() {
+            String::from(control.label())
+        } else {
+            format!("Player {}: {}", player + 1, control.label())
+        };
+        let current = format!("Now: {}", self.bindings.keys(player, control).join(", "));
+        let hint = match &self.refused {
+            Some(key) => format!("{} is reserved, press another key", key),
+            None => format!(
+                "Press a key, {} to keep, {} to cancel",
+                Rebinding::SKIP_KEY,
+                Rebinding::CANCEL_KEY
+            ),
+        };
+
+        let lines = [
+            (
+                title,
+                Game::TEXT_SIZE,
+                Rebinding::TEXT_COLOR,
+                -Rebinding::TITLE_TOP_PADDING,
+            ),
+            (
+                current,
+                Game::HIGH_SCORE_TEXT_SIZE,
+                Rebinding::HINT_COLOR,
+                -Rebinding::CURRENT_TOP_PADDING,
+            ),
+            (
+                hint,
+                Game::HIGH_SCORE_TEXT_SIZE,
+                Rebinding::HINT_COLOR,
+                Rebinding::HINT_TOP_PADDING,
+            ),
+        ];
+        for (content, font_size, color, offset) in lines {
+            frame.push(
+                Layer::Hud,
+                Primitive::Text {
+                    content,
+                    position: Vector::new(width / 2.0, height / 2.0 + offset),
+                    font_size,
+                    anchor: Anchor::Center,
+                    color,
+                },
+            );
+        }
+
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryStorage;
+
+    #[test]
+    fn inputs_should_send_player_two_keys_to_player_one_in_solo_games() {
+        // Arrange
+        let bindings = KeyBindings::default();
+
+        // Act
+        let solo = bindings.inputs("A", 1);
+        let coop = bindings.inputs("a", 2);
+
+        // Assert
+        assert_eq!(
+            solo,
+            vec![ReplayInput::Move(Game::PLAYER_ONE, Direction::LEFT)]
+        );
+        assert_eq!(
+            coop,
+            vec![ReplayInput::Move(Game::PLAYER_TWO, Direction::LEFT)]
+        );
+    }
+
+    #[test]
+    fn inputs_should_change_the_mode_before_starting_the_game() {
+        // Arrange
+        let bindings = KeyBindings::default();
+
+        // Act
+        let inputs = bindings.inputs("2", 1);
+
+        // Assert
+        assert_eq!(inputs, vec![ReplayInput::Start(GameMode::Coop)]);
+    }
+
+    #[test]
+    fn inputs_should_send_the_any_key_start_for_unbound_keys_only() {
+        // Arrange
+        let bindings = KeyBindings::default();
+
+        // Act
+        let unbound = bindings.inputs("X", 1);
+        let pause = bindings.inputs("P", 1);
+
+        // Assert
+        assert_eq!(unbound, vec![ReplayInput::KeyPressed]);
+        assert_eq!(pause, vec![ReplayInput::Pause]);
+    }
+
+    #[test]
+    fn inputs_should_not_start_a_game_when_pause_is_pressed_on_the_title_screen() {
+        // Arrange
+        let bindings = KeyBindings::default();
+        let mut game = Game::new(800.0, 600.0, Box::new(MemoryStorage::default())).unwrap();
+
+        // Act
+        for input in bindings.inputs("P", 1) {
+            input.apply(&mut game);
+        }
+
+        // Assert
+        assert!(!game.is_in_game());
+        assert!(!game.is_paused());
+    }
+
+    #[test]
+    fn inputs_should_confirm_with_enter() {
+        // Arrange
+        let bindings = KeyBindings::default();
+
+        // Act
+        let inputs = bindings.inputs("Enter", 1);
+
+        // Assert
+        assert_eq!(inputs, vec![ReplayInput::Confirm]);
+    }
+
+    #[test]
+    fn bind_should_refuse_reserved_keys() {
+        // Arrange
+        let mut bindings = KeyBindings::default();
+
+        // Act
+        let result = bindings.bind(Game::PLAYER_ONE, Control::Fire, "f9");
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(
+            bindings.keys(Game::PLAYER_ONE, Control::Fire),
+            vec!["Space"]
+        );
+    }
+
+    #[test]
+    fn character_key_should_name_punctuation_as_the_desktop_frontend() {
+        // Act
+        let names: Vec<String> = ['a', ',', '/', '7']
+            .into_iter()
+            .map(KeyBindings::character_key)
+            .collect();
+
+        // Assert
+        assert_eq!(names, vec!["A", "Comma", "Slash", "7"]);
+    }
+
+    #[test]
+    fn is_bound_should_only_report_keys_bound_to_a_control() {
+        // Arrange
+        let bindings = KeyBindings::default();
+
+        // Act
+        let bound = bindings.is_bound("space");
+        let unbound = bindings.is_bound("Tab");
+
+        // Assert
+        assert!(bound);
+        assert!(!unbound);
+    }
+
+    #[test]
+    fn accepts_repeat_should_only_refuse_hotkeys_and_rebinding_keys() {
+        // Act
+        let steering = KeyBindings::accepts_repeat("Left", false);
+        let hotkey = KeyBindings::accepts_repeat("F9", false);
+        let rebinding = KeyBindings::accepts_repeat("Left", true);
+
+        // Assert
+        assert!(steering);
+        assert!(!hotkey);
+        assert!(!rebinding);
+    }
+
+    #[test]
+    fn bind_should_replace_the_keys_of_the_control_and_steal_the_key() {
+        // Arrange
+        let mut bindings = KeyBindings::default();
+
+        // Act
+        bindings
+            .bind(Game::PLAYER_ONE, Control::MoveLeft, "A")
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            bindings.keys(Game::PLAYER_ONE, Control::MoveLeft),
+            vec!["A"]
+        );
+        assert_eq!(
+            bindings.keys(Game::PLAYER_TWO, Control::MoveLeft),
+            vec!["Q"]
+        );
+    }
+
+    #[test]
+    fn from_ron_should_read_what_to_ron_writes() {
+        // Arrange
+        let mut bindings = KeyBindings::default();
+        bindings.bind(Game::PLAYER_TWO, Control::Fire, "E").unwrap();
+
+        // Act
+        let parsed = KeyBindings::from_ron(&bindings.to_ron()).unwrap();
+
+        // Assert
+        assert_eq!(parsed, bindings);
+    }
+
+    #[test]
+    fn from_ron_should_reject_reserved_keys() {
+        // Arrange
+        let mut bindings = KeyBindings::default();
+        bindings.bindings[0].key = String::from("F11");
+
+        // Act
+        let result = KeyBindings::from_ron(&bindings.to_ron());
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid { field: "key", .. })
+        ));
+    }
+
+    #[test]
+    fn press_should_walk_through_every_control() {
+        // Arrange
+        let mut rebinding = Rebinding::new(KeyBindings::default());
+        let slots = Rebinding::slots().len();
+
+        // Act
+        rebinding.press("J");
+        for _ in 1..slots {
+            rebinding.press(Rebinding::SKIP_KEY);
+        }
+
+        // Assert
+        assert!(rebinding.is_done());
+        let bindings = rebinding.bindings();
+        assert_eq!(
+            bindings.keys(Game::PLAYER_ONE, Control::MoveLeft),
+            vec!["J"]
+        );
+        assert_eq!(
+            bindings.keys(Game::PLAYER_ONE, Control::Fire),
+            vec!["Space"]
+        );
+    }
+
+    #[test]
+    fn press_should_ask_again_after_a_reserved_key() {
+        // Arrange
+        let mut rebinding = Rebinding::new(KeyBindings::default());
+
+        // Act
+        rebinding.press("F10");
+
+        // Assert
+        assert_eq!(
+            rebinding.current(),
+            Some((Game::PLAYER_ONE, Control::MoveLeft))
+        );
+        assert!(!rebinding.is_cancelled());
+    }
+
+    #[test]
+    fn press_should_cancel_with_the_cancel_key() {
+        // Arrange
+        let mut rebinding = Rebinding::new(KeyBindings::default());
+
+        // Act
+        rebinding.press("J");
+        rebinding.press(Rebinding::CANCEL_KEY);
+
+        // Assert
+        assert!(rebinding.is_cancelled());
+    }
+}
@@ -0,0 +1,623 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::physics::MovementTuning;
+use crate::Game;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    Parse(String),
+    Invalid {
+        field: &'static str,
+        reason: &'static str,
+    },
+    Wave {
+        wave: usize,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Parse(message) => write!(f, "invalid configuration: {}", message),
+            ConfigError::Invalid { field, reason } => {
+                write!(f, "invalid configuration: `{}` {}", field, reason)
+            }
+            ConfigError::Wave { wave, reason } => {
+                write!(f, "invalid wave {}: {}", wave + 1, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeroConfig {
+    pub size: f64,
+    pub attack_cooldown: u32,
+    pub lives: u32,
+    pub coop_lives: u32,
+    pub respawn_invulnerability: u32,
+    pub movement: MovementTuning,
+    pub versus_movement: MovementTuning,
+}
+
+impl HeroConfig {
+    pub const DEFAULT: HeroConfig = HeroConfig {
+        size: 25.0,
+        attack_cooldown: 8,
+        lives: 1,
+        coop_lives: 3,
+        respawn_invulnerability: 60,
+        movement: MovementTuning::ARCADE,
+        versus_movement: MovementTuning::ARCADE,
+    };
+}
+
+impl Default for HeroConfig {
+    fn default() -> HeroConfig {
+        HeroConfig::DEFAULT
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnemyConfig {
+    pub size: f64,
+    pub attack_rate: f64,
+    pub x_speed: f64,
+    pub y_speed: f64,
+    pub side_speed: f64,
+    pub aimed_shot_rate: f64,
+    pub spread_shot_rate: f64,
+    pub spread_shot_count: u32,
+    pub spread_angle: f64,
+}
+
+impl EnemyConfig {
+    pub const DEFAULT: EnemyConfig = EnemyConfig {
+        size: 20.0,
+        attack_rate: 0.01,
+        x_speed: 45.0,
+        y_speed: 1.0,
+        side_speed: 2.0,
+        aimed_shot_rate: 0.0,
+        spread_shot_rate: 0.0,
+        spread_shot_count: 3,
+        spread_angle: std::f64::consts::FRAC_PI_4,
+    };
+}
+
+impl Default for EnemyConfig {
+    fn default() -> EnemyConfig {
+        EnemyConfig::DEFAULT
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShotConfig {
+    pub speed: f64,
+    pub size: f64,
+}
+
+impl ShotConfig {
+    pub const DEFAULT: ShotConfig = ShotConfig {
+        speed: 10.0,
+        size: 5.0,
+    };
+}
+
+impl Default for ShotConfig {
+    fn default() -> ShotConfig {
+        ShotConfig::DEFAULT
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreConfig {
+    pub points: u32,
+    pub combo_window: u32,
+    pub max_multiplier: u32,
+    pub close_kill_distance: f64,
+    pub close_kill_bonus: u32,
+    pub accuracy_bonus_per_percent: u32,
+    /// Shots a player must fire before accuracy earns a bonus.
+    pub accuracy_min_shots: u32,
+}
+
+impl ScoreConfig {
+    pub const DEFAULT: ScoreConfig = ScoreConfig {
+        points: 10,
+        combo_window: 80,
+        max_multiplier: 8,
+        close_kill_distance: 150.0,
+        close_kill_bonus: 15,
+        accuracy_bonus_per_percent: 5,
+        accuracy_min_shots: 20,
+    };
+}
+
+impl Default for ScoreConfig {
+    fn default() -> ScoreConfig {
+        ScoreConfig::DEFAULT
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    pub width: f64,
+    pub height: f64,
+    pub star_count: u32,
+    pub versus_time_limit: u32,
+    pub attract_delay: u32,
+    pub attract_duration: u32,
+    /// Level games start at, skipping the waves before it.
+    pub starting_level: u32,
+    pub hero: HeroConfig,
+    pub enemy: EnemyConfig,
+    pub shot: ShotConfig,
+    pub score: ScoreConfig,
+}
+
+impl GameConfig {
+    pub const MAX_STAR_COUNT: u32 = 10_000;
+    pub const MAX_STARTING_LEVEL: u32 = 99;
+    pub const MIN_SCREEN_SIZE: f64 = 1.0;
+    pub const MAX_POINTS: u32 = 1_000;
+    pub const MAX_MULTIPLIER: u32 = 16;
+    pub const MAX_SPREAD_SHOT_COUNT: u32 = 16;
+
+    pub const DEFAULT: GameConfig = GameConfig {
+        width: Game::LOGICAL_WIDTH,
+        height: Game::LOGICAL_HEIGHT,
+        star_count: 100,
+        versus_time_limit: 90 * Game::TICKS_PER_SECOND,
+        attract_delay: 10 * Game::TICKS_PER_SECOND,
+        attract_duration: 30 * Game::TICKS_PER_SECOND,
+        starting_level: Game::INITIAL_LEVEL,
+        hero: HeroConfig::DEFAULT,
+        enemy: EnemyConfig::DEFAULT,
+        shot: ShotConfig::DEFAULT,
+        score: ScoreConfig::DEFAULT,
+    };
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        screen_size("width", self.width)?;
+        screen_size("height", self.height)?;
+        check(
+            "star_count",
+            self.star_count <= GameConfig::MAX_STAR_COUNT,
+            "must not exceed 10000",
+        )?;
+        check(
+            "versus_time_limit",
+            self.versus_time_limit > 0,
+            "must be at least one tick",
+        )?;
+        check(
+            "attract_delay",
+            self.attract_delay > 0,
+            "must be at least one tick",
+        )?;
+        check(
+            "attract_duration",
+            self.attract_duration > 0,
+            "must be at least one tick",
+        )?;
+        check(
+            "starting_level",
+            (Game::INITIAL_LEVEL..=GameConfig::MAX_STARTING_LEVEL).contains(&self.starting_level),
+            "must be between 1 and 99",
+        )?;
+
+        positive("hero.size", self.hero.size)?;
+        check("hero.lives", self.hero.lives > 0, "must be at least one")?;
+        check(
+            "hero.coop_lives",
+            self.hero.coop_lives > 0,
+            "must be at least one",
+        )?;
+        movement(
+            [
+                "hero.movement.acceleration",
+                "hero.movement.max_speed",
+                "hero.movement.drag",
+                "hero.movement.dash_speed",
+            ],
+            &self.hero.movement,
+        )?;
+        movement(
+            [
+                "hero.versus_movement.acceleration",
+                "hero.versus_movement.max_speed",
+                "hero.versus_movement.drag",
+                "hero.versus_movement.dash_speed",
+            ],
+            &self.hero.versus_movement,
+        )?;
+
+        positive("enemy.size", self.enemy.size)?;
+        check(
+            "enemy.size",
+            self.enemy.size * 2.0 < self.width,
+            "must be less than half the width",
+        )?;
+        probability("enemy.attack_rate", self.enemy.attack_rate)?;
+        non_negative("enemy.x_speed", self.enemy.x_speed)?;
+        non_negative("enemy.y_speed", self.enemy.y_speed)?;
+        positive("enemy.side_speed", self.enemy.side_speed)?;
+        probability("enemy.aimed_shot_rate", self.enemy.aimed_shot_rate)?;
+        probability("enemy.spread_shot_rate", self.enemy.spread_shot_rate)?;
+        check(
+            "enemy.spread_shot_count",
+            (1..=GameConfig::MAX_SPREAD_SHOT_COUNT).contains(&self.enemy.spread_shot_count),
+            "must be between 1 and 16",
+        )?;
+        non_negative("enemy.spread_angle", self.enemy.spread_angle)?;
+
+        positive("shot.speed", self.shot.speed)?;
+        positive("shot.size", self.shot.size)?;
+
+        points("score.points", self.score.points)?;
+        check(
+            "score.combo_window",
+            self.score.combo_window > 0,
+            "must be at least one tick",
+        )?;
+        check(
+            "score.max_multiplier",
+            (1..=GameConfig::MAX_MULTIPLIER).contains(&self.score.max_multiplier),
+            "must be between 1 and 16",
+        )?;
+        non_negative("score.close_kill_distance", self.score.close_kill_distance)?;
+        points("score.close_kill_bonus", self.score.close_kill_bonus)?;
+        points(
+            "score.accuracy_bonus_per_percent",
+            self.score.accuracy_bonus_per_percent,
+        )
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> GameConfig {
+        GameConfig::DEFAULT
+    }
+}
+
+/// Presets layered on top of a configuration: they change the lives of the
+/// heroes, how their ships handle and how often enemies fire.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const EASY_EXTRA_LIVES: u32 = 2;
+    pub const EASY_ATTACK_FACTOR: f64 = 0.5;
+    pub const HARD_ATTACK_FACTOR: f64 = 1.5;
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Difficulty> {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+            .into_iter()
+            .find(|difficulty| difficulty.name() == name)
+    }
+
+    /// Easy games handle snappily and hard ones floatily, whatever the
+    /// configured tuning.
+    pub fn movement(&self) -> Option<MovementTuning> {
+        match *self {
+            Difficulty::Easy => Some(MovementTuning::ARCADE),
+            Difficulty::Normal => None,
+            Difficulty::Hard => Some(MovementTuning::FLOATY),
+        }
+    }
+
+    pub fn apply(&self, config: GameConfig) -> GameConfig {
+        let mut config = config;
+        if let Some(tuning) = self.movement() {
+            config.hero.movement = tuning;
+            config.hero.versus_movement = tuning;
+        }
+        match *self {
+            Difficulty::Easy => {
+                config.hero.lives = config
+                    .hero
+                    .lives
+                    .saturating_add(Difficulty::EASY_EXTRA_LIVES);
+                config.hero.coop_lives = config
+                    .hero
+                    .coop_lives
+                    .saturating_add(Difficulty::EASY_EXTRA_LIVES);
+                config.enemy.attack_rate *= Difficulty::EASY_ATTACK_FACTOR;
+            }
+            Difficulty::Normal => {}
+            Difficulty::Hard => {
+                config.hero.lives = config.hero.lives.saturating_sub(1).max(1);
+                config.hero.coop_lives = config.hero.coop_lives.saturating_sub(1).max(1);
+                config.enemy.attack_rate =
+                    (config.enemy.attack_rate * Difficulty::HARD_ATTACK_FACTOR).min(1.0);
+            }
+        }
+        config
+    }
+}
+
+fn check(field: &'static str, valid: bool, reason: &'static str) -> Result<(), ConfigError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(ConfigError::Invalid { field, reason })
+    }
+}
+
+fn positive(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    check(
+        field,
+        value.is_finite() && value > 0.0,
+        "must be a positive number",
+    )
+}
+
+fn screen_size(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    check(
+        field,
+        value.is_finite() && value >= GameConfig::MIN_SCREEN_SIZE,
+        "must be at least one pixel",
+    )
+}
+
+fn points(field: &'static str, value: u32) -> Result<(), ConfigError> {
+    check(
+        field,
+        value <= GameConfig::MAX_POINTS,
+        "must not exceed 1000",
+    )
+}
+
+fn non_negative(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    check(
+        field,
+        value.is_finite() && value >= 0.0,
+        "must not be negative",
+    )
+}
+
+fn movement(fields: [&'static str; 4], tuning: &MovementTuning) -> Result<(), ConfigError> {
+    let [acceleration, max_speed, drag, dash_speed] = fields;
+    non_negative(acceleration, tuning.acceleration)?;
+    positive(max_speed, tuning.max_speed)?;
+    check(
+        drag,
+        (0.0..=1.0).contains(&tuning.drag),
+        "must be between 0 and 1",
+    )?;
+    non_negative(dash_speed, tuning.dash_speed)
+}
+
+fn probability(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    check(
+        field,
+        (0.0..=1.0).contains(&value),
+        "must be between 0 and 1",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_should_be_valid() {
+        // Act
+        let result = GameConfig::default().validate();
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn validate_should_name_the_invalid_field() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.enemy.attack_rate = 1.5;
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "enemy.attack_rate",
+                reason: "must be between 0 and 1",
+            })
+        );
+    }
+
+    #[test]
+    fn validate_should_reject_a_zero_sized_screen() {
+        // Arrange
+        let config = GameConfig {
+            width: 0.0,
+            ..GameConfig::default()
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid { field: "width", .. })
+        ));
+    }
+
+    #[test]
+    fn validate_should_reject_a_screen_narrower_than_a_pixel() {
+        // Arrange
+        let config = GameConfig {
+            width: 0.5,
+            ..GameConfig::default()
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid { field: "width", .. })
+        ));
+    }
+
+    #[test]
+    fn validate_should_reject_enemies_as_wide_as_the_screen() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.enemy.size = config.width / 2.0;
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "enemy.size",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn validate_should_reject_points_that_could_overflow_the_score() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.score.points = u32::MAX;
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "score.points",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn validate_should_reject_too_many_spread_shots() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.enemy.spread_shot_count = u32::MAX;
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "enemy.spread_shot_count",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn validate_should_check_the_versus_movement() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.hero.versus_movement.drag = 2.0;
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "hero.versus_movement.drag",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn validate_should_reject_a_starting_level_out_of_range() {
+        // Arrange
+        let config = GameConfig {
+            starting_level: 0,
+            ..GameConfig::default()
+        };
+
+        // Act
+        let result = config.validate();
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "starting_level",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn difficulty_should_adjust_lives_and_enemy_fire() {
+        // Arrange
+        let config = GameConfig::default();
+
+        // Act
+        let easy = Difficulty::Easy.apply(config);
+        let hard = Difficulty::Hard.apply(config);
+
+        // Assert
+        assert_eq!(Difficulty::Normal.apply(config), config);
+        assert_eq!(easy.hero.lives, 3);
+        assert_eq!(easy.hero.coop_lives, 5);
+        assert!(easy.enemy.attack_rate < config.enemy.attack_rate);
+        assert_eq!(hard.hero.lives, 1);
+        assert_eq!(hard.hero.coop_lives, 2);
+        assert!(hard.enemy.attack_rate > config.enemy.attack_rate);
+        assert_eq!(hard.hero.movement, MovementTuning::FLOATY);
+        assert_eq!(hard.hero.versus_movement, MovementTuning::FLOATY);
+        assert_eq!(hard.validate(), Ok(()));
+    }
+
+    #[test]
+    fn difficulty_should_not_overflow_the_lives() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.hero.lives = u32::MAX;
+        config.hero.coop_lives = u32::MAX;
+
+        // Act
+        let easy = Difficulty::Easy.apply(config);
+
+        // Assert
+        assert_eq!(easy.hero.lives, u32::MAX);
+        assert_eq!(easy.hero.coop_lives, u32::MAX);
+    }
+}
@@ -1,49 +1,118 @@
+use crate::config::{EnemyConfig, GameConfig};
 use crate::game_objects::*;
 use crate::player::*;
 use crate::spaceship::*;
-use rand::{thread_rng, Rng};
+use crate::waves::{Arrival, EnemyKind, Entry};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 pub struct Enemy {
     spaceship: Spaceship,
+    config: EnemyConfig,
+    kind: EnemyKind,
     attack_rate: f64,
+    target: Option<Position>,
+    drift: Velocity,
+    random: StdRng,
 }
 
 impl Enemy {
-    pub const INITIAL_ATTACK_RATE: f64 = 0.01;
     pub const COLOR: Color = [0.0, 0.0, 1.0, 1.0];
-    pub const X_SPEED: f64 = 45.0;
-    pub const Y_SPEED: f64 = 1.0;
     pub const Y_START: f64 = -15.0;
-    pub const SIZE: f64 = 20.0;
     pub const DIRECTION: Direction = Direction::DOWN;
+    pub const SIDE_BAND: (f64, f64) = (0.35, 0.65);
 
-    pub fn new(screen_size: ScreenSize) -> Enemy {
-        let mut random = thread_rng();
+    pub fn enter(
+        screen_size: ScreenSize,
+        config: &GameConfig,
+        arrival: &Arrival,
+        random: &mut StdRng,
+    ) -> Enemy {
+        let (width, height) = screen_size;
+        let size = config.enemy.size;
+        let side_speed = config.enemy.side_speed;
+        let mut side_y = |y: Option<f64>| -> f64 {
+            let (band_top, band_bottom) = Enemy::SIDE_BAND;
+            let y = y.unwrap_or_else(|| random.gen_range(band_top..band_bottom));
+            height * y + arrival.offset
+        };
+
+        let (position, drift) = match arrival.entry {
+            Entry::Top { x } => {
+                let x = x.map_or_else(|| random.gen_range(size..width - size), |x| width * x);
+                (
+                    Vector::new(x + arrival.offset, Enemy::Y_START),
+                    Vector::new(0.0, config.enemy.y_speed),
+                )
+            }
+            Entry::Left { y } => (Vector::new(0.0, side_y(y)), Vector::new(side_speed, 0.0)),
+            Entry::Right { y } => (Vector::new(width, side_y(y)), Vector::new(-side_speed, 0.0)),
+            Entry::Side { y } => {
+                let y = side_y(y);
+                if random.gen_bool(0.5) {
+                    (Vector::new(0.0, y), Vector::new(side_speed, 0.0))
+                } else {
+                    (Vector::new(width, y), Vector::new(-side_speed, 0.0))
+                }
+            }
+        };
+
+        let direction = match arrival.entry {
+            Entry::Top { .. } => Enemy::DIRECTION,
+            _ if random.gen_bool(0.5) => Direction::UP,
+            _ => Direction::DOWN,
+        };
+
+        Enemy::spawn(
+            config,
+            arrival.kind,
+            position,
+            direction,
+            drift,
+            screen_size,
+            StdRng::seed_from_u64(random.gen()),
+        )
+    }
 
-        let (width, _) = screen_size;
-        let min_x = Enemy::SIZE;
-        let max_x = width - Enemy::SIZE;
-        let gen_x = random.gen_range(min_x..max_x);
+    fn spawn(
+        config: &GameConfig,
+        kind: EnemyKind,
+        position: Position,
+        direction: Direction,
+        drift: Velocity,
+        screen_size: ScreenSize,
+        random: StdRng,
+    ) -> Enemy {
+        let mut spaceship = Spaceship::new(
+            position,
+            direction,
+            screen_size,
+            Enemy::COLOR,
+            config.enemy.size,
+        );
+        spaceship.set_shot_config(config.shot);
 
         Enemy {
-            attack_rate: Enemy::INITIAL_ATTACK_RATE,
-            spaceship: Spaceship::new(
-                (gen_x, Enemy::Y_START),
-                Enemy::DIRECTION,
-                screen_size,
-                Enemy::COLOR,
-                Enemy::SIZE,
-            ),
+            config: config.enemy,
+            kind,
+            attack_rate: config.enemy.attack_rate,
+            target: None,
+            drift,
+            random,
+            spaceship,
         }
     }
 
-    fn calculate_x_move(&self) -> f64 {
-        let mut random = thread_rng();
-        let (curr_x, _) = self.spaceship.position();
+    pub fn aim_at(&mut self, target: Position) {
+        self.target = Some(target);
+    }
 
-        let movement = if random.gen_bool(0.05) {
-            let move_range = 2.0 * Enemy::X_SPEED;
-            random.gen_range(0.0..move_range) - Enemy::X_SPEED
+    fn calculate_x_move(&mut self) -> f64 {
+        let curr_x = self.spaceship.position().x;
+
+        let movement = if self.random.gen_bool(0.05) {
+            let move_range = 2.0 * self.config.x_speed;
+            self.random.gen_range(0.0..move_range) - self.config.x_speed
         } else {
             0.0
         };
@@ -52,23 +121,43 @@ impl Enemy {
     }
 
     fn calculate_y_move(&self) -> f64 {
-        let (_, curr_y) = self.spaceship.position();
-        curr_y + Enemy::Y_SPEED
+        self.spaceship.position().y + self.drift.y
     }
 
     fn move_spaceship(&mut self) {
+        if self.drift.x != 0.0 {
+            self.spaceship
+                .move_to(self.spaceship.position() + self.drift);
+            return;
+        }
+
         let (width, _) = self.spaceship.screen_size();
 
         let new_x = max(min(self.calculate_x_move(), width), 0.0);
         let new_y = self.calculate_y_move();
 
-        self.spaceship.move_to((new_x, new_y));
+        self.spaceship.move_to(Vector::new(new_x, new_y));
     }
 
     fn attack(&mut self) {
-        let mut random = thread_rng();
-        if random.gen_bool(self.attack_rate) {
-            self.spaceship.fire();
+        if !self.random.gen_bool(self.attack_rate) {
+            return;
+        }
+
+        let spread_count = self.config.spread_shot_count;
+        let spread_angle = self.config.spread_angle;
+        match (self.kind, self.target) {
+            (EnemyKind::Sniper, Some(target)) => self.spaceship.fire_at(target),
+            (EnemyKind::Spreader, _) => self.spaceship.fire_spread(spread_count, spread_angle),
+            (EnemyKind::Fighter, Some(target))
+                if self.random.gen_bool(self.config.aimed_shot_rate) =>
+            {
+                self.spaceship.fire_at(target)
+            }
+            (EnemyKind::Fighter, Some(_)) if self.random.gen_bool(self.config.spread_shot_rate) => {
+                self.spaceship.fire_spread(spread_count, spread_angle)
+            }
+            _ => self.spaceship.fire(),
         }
     }
 }
@@ -94,10 +183,34 @@ mod tests {
     use super::*;
     use crate::spaceship::tests::*;
 
+    const CONFIG: GameConfig = GameConfig::DEFAULT;
+
+    fn arrival(kind: EnemyKind, entry: Entry) -> Arrival {
+        Arrival {
+            tick: 0,
+            kind,
+            entry,
+            offset: 0.0,
+        }
+    }
+
+    fn enter(arrival: &Arrival) -> Enemy {
+        Enemy::enter(
+            (800.0, 600.0),
+            &CONFIG,
+            arrival,
+            &mut StdRng::seed_from_u64(0),
+        )
+    }
+
+    fn fighter() -> Enemy {
+        enter(&arrival(EnemyKind::Fighter, Entry::default()))
+    }
+
     #[test]
     fn attack_should_fire_spaceship_when_rate_is_one() {
         // Arrange
-        let mut enemy = Enemy::new((800.0, 600.0));
+        let mut enemy = fighter();
         enemy.attack_rate = 1.0;
 
         // Act
@@ -107,10 +220,25 @@ mod tests {
         assert_eq!(spacheship_shots(enemy.spaceship).len(), 1);
     }
 
+    #[test]
+    fn enter_should_drift_horizontally_across_the_screen_from_a_side() {
+        // Arrange
+        let mut enemy = enter(&arrival(EnemyKind::Fighter, Entry::Side { y: None }));
+        let start = enemy.spaceship.position();
+
+        // Act
+        enemy.move_spaceship();
+
+        // Assert
+        let position = enemy.spaceship.position();
+        assert_eq!(position.y, start.y);
+        assert_eq!((position.x - start.x).abs(), CONFIG.enemy.side_speed);
+    }
+
     #[test]
     fn attack_should_not_fire_spaceship_when_rate_is_zero() {
         // Arrange
-        let mut enemy = Enemy::new((800.0, 600.0));
+        let mut enemy = fighter();
         enemy.attack_rate = 0.0;
 
         // Act
@@ -119,4 +247,49 @@ mod tests {
         // Assert
         assert_eq!(spacheship_shots(enemy.spaceship).len(), 0);
     }
+
+    #[test]
+    fn enter_should_place_the_enemy_at_its_entry_position() {
+        // Arrange
+        let mut arrival = arrival(EnemyKind::Fighter, Entry::Top { x: Some(0.25) });
+        arrival.offset = 10.0;
+
+        // Act
+        let enemy = enter(&arrival);
+
+        // Assert
+        assert_eq!(
+            enemy.spaceship.position(),
+            Vector::new(210.0, Enemy::Y_START)
+        );
+    }
+
+    #[test]
+    fn attack_should_fire_straight_when_a_fighter_has_no_target() {
+        // Arrange
+        let mut enemy = fighter();
+        enemy.attack_rate = 1.0;
+        enemy.config.spread_shot_rate = 1.0;
+
+        // Act
+        enemy.attack();
+
+        // Assert
+        assert_eq!(spacheship_shots(enemy.spaceship).len(), 1);
+    }
+
+    #[test]
+    fn attack_should_always_aim_when_the_enemy_is_a_sniper() {
+        // Arrange
+        let mut enemy = enter(&arrival(EnemyKind::Sniper, Entry::Top { x: Some(0.5) }));
+        enemy.attack_rate = 1.0;
+        enemy.aim_at(Vector::new(0.0, 600.0));
+
+        // Act
+        enemy.attack();
+
+        // Assert
+        let velocity = shot_velocity(spacheship_shots(enemy.spaceship)[0]);
+        assert!(velocity.x < 0.0 && velocity.y > 0.0);
+    }
 }
@@ -0,0 +1,292 @@
+use crate::game_objects::*;
+use crate::{Game, GameConfig, GameMode, GameScore, MemoryStorage};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Action {
+    Noop,
+    Left,
+    Right,
+    Up,
+    Down,
+    Fire,
+    Dash,
+}
+
+impl Action {
+    pub const ALL: [Action; 7] = [
+        Action::Noop,
+        Action::Left,
+        Action::Right,
+        Action::Up,
+        Action::Down,
+        Action::Fire,
+        Action::Dash,
+    ];
+
+    pub fn from_index(index: usize) -> Option<Action> {
+        Action::ALL.get(index).copied()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Observation {
+    pub features: Vec<f32>,
+    pub grid: Vec<f32>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StepInfo {
+    pub score: GameScore,
+    pub lives: u32,
+    pub kills: u32,
+    pub shots_fired: u32,
+    pub ticks: u64,
+}
+
+pub struct Env {
+    game: Game,
+    ticks: u64,
+    last_score: GameScore,
+    last_lives: u32,
+}
+
+impl Env {
+    pub const NEAREST_ENEMIES: usize = 5;
+    pub const NEAREST_SHOTS: usize = 5;
+    pub const FEATURE_COUNT: usize = 4 + 3 * (Env::NEAREST_ENEMIES + Env::NEAREST_SHOTS);
+    pub const GRID_WIDTH: usize = 80;
+    pub const GRID_HEIGHT: usize = 60;
+    pub const LIFE_LOST_PENALTY: f64 = 50.0;
+    pub const MAX_EPISODE_TICKS: u64 = 5 * 60 * Game::TICKS_PER_SECOND as u64;
+
+    pub fn new() -> Env {
+        Env {
+            game: Env::new_game(),
+            ticks: 0,
+            last_score: 0,
+            last_lives: GameConfig::DEFAULT.hero.lives,
+        }
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        self.game = Env::new_game();
+        self.game.seed_random(seed);
+        self.ticks = 0;
+        self.last_score = 0;
+        self.last_lives = self.game.lives(Game::PLAYER_ONE);
+
+        self.observation()
+    }
+
+    pub fn step(&mut self, action: Action) -> (Observation, f64, bool, StepInfo) {
+        if self.is_done() {
+            return (self.observation(), 0.0, true, self.info());
+        }
+
+        self.apply(action);
+        self.game.next_turn();
+        self.ticks += 1;
+
+        let info = self.info();
+        let mut reward = f64::from(info.score) - f64::from(self.last_score);
+        if info.lives < self.last_lives {
+            reward -= Env::LIFE_LOST_PENALTY * f64::from(self.last_lives - info.lives);
+        }
+        self.last_score = info.score;
+        self.last_lives = info.lives;
+
+        (self.observation(), reward, self.is_done(), info)
+    }
+
+    pub fn observation(&self) -> Observation {
+        Observation {
+            features: self.features(),
+            grid: self.grid(),
+        }
+    }
+
+    fn new_game() -> Game {
+        let mut game = Game::new(
+            Game::LOGICAL_WIDTH,
+            Game::LOGICAL_HEIGHT,
+            Box::new(MemoryStorage::default()),
+        )
+        .expect("The logical screen size is valid");
+        game.start(GameMode::Solo);
+        game
+    }
+
+    fn is_done(&self) -> bool {
+        !self.game.is_in_game() || self.ticks >= Env::MAX_EPISODE_TICKS
+    }
+
+    fn apply(&mut self, action: Action) {
+        let player = Game::PLAYER_ONE;
+        match action {
+            Action::Noop => {}
+            Action::Left => self.game.move_player(player, Direction::LEFT),
+            Action::Right => self.game.move_player(player, Direction::RIGHT),
+            Action::Up => self.game.move_player(player, Direction::UP),
+            Action::Down => self.game.move_player(player, Direction::DOWN),
+            Action::Fire => self.game.fire_player_attack(player),
+            Action::Dash => self.game.dash_player(player),
+        }
+    }
+
+    fn info(&self) -> StepInfo {
+        let stats = self
+            .game
+            .player_stats(Game::PLAYER_ONE)
+            .expect("Environment game must have a first player");
+
+        StepInfo {
+            score: stats.score,
+            lives: self.game.lives(Game::PLAYER_ONE),
+            kills: stats.kills,
+            shots_fired: stats.shots_fired,
+            ticks: self.ticks,
+        }
+    }
+
+    fn features(&self) -> Vec<f32> {
+        let (width, height) = self.game.screen_size();
+        let hero = self.game.hero_position(Game::PLAYER_ONE);
+        let origin = hero.unwrap_or(Vector::ZERO);
+
+        let mut features = Vec::with_capacity(Env::FEATURE_COUNT);
+        features.push((origin.x / width) as f32);
+        features.push((origin.y / height) as f32);
+        features
+            .push(self.game.lives(Game::PLAYER_ONE) as f32 / self.game.config().hero.lives as f32);
+        features.push(if hero.is_some() { 1.0 } else { 0.0 });
+
+        let mut nearest = |positions: Vec<Position>, count: usize| {
+            let mut positions = positions;
+            positions.sort_by(|a, b| {
+                a.distance(origin)
+                    .partial_cmp(&b.distance(origin))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+
+            for i in 0..count {
+                match positions.get(i) {
+                    Some(position) => {
+                        let offset = *position - origin;
+                        features.push((offset.x / width) as f32);
+                        features.push((offset.y / height) as f32);
+                        features.push(1.0);
+                    }
+                    None => features.extend([0.0, 0.0, 0.0]),
+                }
+            }
+        };
+        nearest(self.game.enemy_positions(), Env::NEAREST_ENEMIES);
+        nearest(self.game.enemy_shot_positions(), Env::NEAREST_SHOTS);
+
+        features
+    }
+
+    fn grid(&self) -> Vec<f32> {
+        let (width, height) = self.game.screen_size();
+        let cell_width = width / Env::GRID_WIDTH as f64;
+        let cell_height = height / Env::GRID_HEIGHT as f64;
+        let column = |x: f64| ((x / cell_width).max(0.0) as usize).min(Env::GRID_WIDTH - 1);
+        let row = |y: f64| ((y / cell_height).max(0.0) as usize).min(Env::GRID_HEIGHT - 1);
+
+        let mut grid = vec![0.0_f32; Env::GRID_WIDTH * Env::GRID_HEIGHT];
+        for object in self.game.space_objects() {
+            let coord = object.coord();
+            let xs = coord.iter().map(|point| point[0]);
+            let ys = coord.iter().map(|point| point[1]);
+            let (left, right) = (xs.clone().fold(f64::MAX, min), xs.fold(f64::MIN, max));
+            let (top, bottom) = (ys.clone().fold(f64::MAX, min), ys.fold(f64::MIN, max));
+
+            if right < 0.0 || bottom < 0.0 || left > width || top > height {
+                continue;
+            }
+
+            let [r, g, b, a] = object.color();
+            let luminance = (0.299 * r + 0.587 * g + 0.114 * b) * a;
+            for y in row(top)..=row(bottom) {
+                for x in column(left)..=column(right) {
+                    let cell = &mut grid[y * Env::GRID_WIDTH + x];
+                    *cell = cell.max(luminance);
+                }
+            }
+        }
+
+        grid
+    }
+}
+
+impl Default for Env {
+    fn default() -> Env {
+        Env::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_should_return_fixed_size_observations() {
+        // Arrange
+        let mut env = Env::new();
+
+        // Act
+        let observation = env.reset(1);
+
+        // Assert
+        assert_eq!(observation.features.len(), Env::FEATURE_COUNT);
+        assert_eq!(observation.grid.len(), Env::GRID_WIDTH * Env::GRID_HEIGHT);
+        assert!(observation.grid.iter().any(|cell| *cell > 0.0));
+    }
+
+    #[test]
+    fn reset_should_make_episodes_repeatable() {
+        // Arrange
+        let mut env = Env::new();
+        let run = |env: &mut Env| -> Vec<f64> {
+            env.reset(9);
+            (0..300)
+                .map(|i| env.step(Action::ALL[i % Action::ALL.len()]).1)
+                .collect()
+        };
+
+        // Act
+        let first = run(&mut env);
+        let second = run(&mut env);
+
+        // Assert
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn step_should_move_the_hero() {
+        // Arrange
+        let mut env = Env::new();
+        let start = env.reset(1).features[0];
+
+        // Act
+        let (observation, _, done, info) = env.step(Action::Left);
+
+        // Assert
+        assert!(observation.features[0] < start);
+        assert!(!done);
+        assert_eq!(info.ticks, 1);
+    }
+
+    #[test]
+    fn from_index_should_reject_unknown_actions() {
+        // Act
+        let action = Action::from_index(Action::ALL.len());
+
+        // Assert
+        assert_eq!(action, None);
+    }
+}
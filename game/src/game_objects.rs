@@ -1,10 +1,15 @@
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+use serde::{Deserialize, Serialize};
+
 pub type Color = [f32; 4];
-pub type Position = (f64, f64);
+pub type Position = Vector;
+pub type Velocity = Vector;
 pub type Coord = Vec<[f64; 2]>;
 pub type ScreenSize = (f64, f64);
 pub type FontSize = u32;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Direction {
     LEFT,
     UP,
@@ -12,6 +17,113 @@ pub enum Direction {
     DOWN,
 }
 
+impl Direction {
+    pub fn vector(&self) -> Vector {
+        match *self {
+            Direction::LEFT => Vector::new(-1.0, 0.0),
+            Direction::UP => Vector::new(0.0, -1.0),
+            Direction::RIGHT => Vector::new(1.0, 0.0),
+            Direction::DOWN => Vector::new(0.0, 1.0),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Vector {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector::new(0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64) -> Vector {
+        Vector { x, y }
+    }
+
+    pub fn from_angle(radians: f64) -> Vector {
+        Vector::new(radians.cos(), radians.sin())
+    }
+
+    pub fn length(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    pub fn angle(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    pub fn normalize(&self) -> Vector {
+        let length = self.length();
+        if length == 0.0 {
+            return Vector::ZERO;
+        }
+
+        Vector::new(self.x / length, self.y / length)
+    }
+
+    pub fn distance(&self, other: Vector) -> f64 {
+        (*self - other).length()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: f64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlayArea {
+    top_left: Position,
+    bottom_right: Position,
+}
+
+impl PlayArea {
+    pub fn new(top_left: Position, bottom_right: Position) -> PlayArea {
+        PlayArea {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    pub fn from_screen_size(screen_size: ScreenSize) -> PlayArea {
+        let (width, height) = screen_size;
+        PlayArea::new(Vector::ZERO, Vector::new(width, height))
+    }
+
+    pub fn clamp(&self, position: Position) -> Position {
+        Vector::new(
+            max(min(position.x, self.bottom_right.x), self.top_left.x),
+            max(min(position.y, self.bottom_right.y), self.top_left.y),
+        )
+    }
+}
+
 const SCREEN_MARGIN: f64 = 20.0;
 
 const MIN_DISTANCE: f64 = 20.0;
@@ -26,17 +138,19 @@ pub trait SpaceObject {
     fn size(&self) -> f64;
 
     fn coord(&self) -> Coord {
-        let (x, y) = self.position();
-        let direction_y: f64 = match self.direction() {
-            Direction::UP => y - self.size(),
-            _ => y + self.size(),
-        };
-
-        vec![[x - self.size(), y], [x, direction_y], [x + self.size(), y]]
+        let Vector { x, y } = self.position();
+        let size = self.size();
+
+        match self.direction() {
+            Direction::LEFT => vec![[x, y - size], [x - size, y], [x, y + size]],
+            Direction::RIGHT => vec![[x, y - size], [x + size, y], [x, y + size]],
+            Direction::UP => vec![[x - size, y], [x, y - size], [x + size, y]],
+            Direction::DOWN => vec![[x - size, y], [x, y + size], [x + size, y]],
+        }
     }
 
     fn is_visible(&self, screen_size: ScreenSize) -> bool {
-        let (x, y) = self.position();
+        let Vector { x, y } = self.position();
         let (width, height) = screen_size;
 
         x < width + SCREEN_MARGIN
@@ -46,10 +160,9 @@ pub trait SpaceObject {
     }
 
     fn check_collision(&self, other: &dyn SpaceObject) -> bool {
-        let (x, y) = self.position();
-        let (other_x, other_y) = other.position();
+        let difference = self.position() - other.position();
 
-        (x - other_x).abs() < MIN_DISTANCE && (y - other_y).abs() < MIN_DISTANCE
+        difference.x.abs() < MIN_DISTANCE && difference.y.abs() < MIN_DISTANCE
     }
 }
 
@@ -104,6 +217,7 @@ pub fn max(x: f64, y: f64) -> f64 {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::neg_multiply)]
 mod tests {
     use super::*;
 
@@ -126,12 +240,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn normalize_should_return_a_unit_vector_with_the_same_angle() {
+        // Arrange
+        let vector = Vector::new(3.0, 4.0);
+
+        // Act
+        let normalized = vector.normalize();
+
+        // Assert
+        assert!((normalized.length() - 1.0).abs() < 1e-9);
+        assert!((normalized.angle() - vector.angle()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn normalize_should_keep_the_zero_vector() {
+        // Arrange
+        let vector = Vector::ZERO;
+
+        // Act
+        let normalized = vector.normalize();
+
+        // Assert
+        assert_eq!(normalized, Vector::ZERO);
+    }
+
+    #[test]
+    fn distance_should_return_the_euclidean_distance() {
+        // Arrange
+        let a = Vector::new(1.0, 1.0);
+        let b = Vector::new(4.0, 5.0);
+
+        // Act
+        let distance = a.distance(b);
+
+        // Assert
+        assert_eq!(distance, 5.0);
+    }
+
+    #[test]
+    fn clamp_should_keep_position_inside_the_play_area() {
+        // Arrange
+        let play_area = PlayArea::new(Vector::new(10.0, 20.0), Vector::new(100.0, 200.0));
+
+        // Act
+        let clamped = play_area.clamp(Vector::new(150.0, 5.0));
+
+        // Assert
+        assert_eq!(clamped, Vector::new(100.0, 20.0));
+    }
+
     #[test]
     fn is_visible_should_return_true_when_object_is_in_the_middle_of_screen() {
         // Arrange
         let (width, height) = (100.0, 100.0);
         let space_object = DummyObject {
-            position: (width / 2.0, height / 2.0),
+            position: Vector::new(width / 2.0, height / 2.0),
         };
 
         // Act
@@ -146,7 +310,7 @@ mod tests {
         // Arrange
         let (width, height) = (100.0, 100.0);
         let space_object = DummyObject {
-            position: (width + SCREEN_MARGIN + 1.0, height / 2.0),
+            position: Vector::new(width + SCREEN_MARGIN + 1.0, height / 2.0),
         };
 
         // Act
@@ -161,7 +325,7 @@ mod tests {
         // Arrange
         let (width, height) = (100.0, 100.0);
         let space_object = DummyObject {
-            position: (-1.0 * SCREEN_MARGIN - 1.0, height / 2.0),
+            position: Vector::new(-1.0 * SCREEN_MARGIN - 1.0, height / 2.0),
         };
 
         // Act
@@ -176,7 +340,7 @@ mod tests {
         // Arrange
         let (width, height) = (100.0, 100.0);
         let space_object = DummyObject {
-            position: (width / 2.0, height + SCREEN_MARGIN + 1.0),
+            position: Vector::new(width / 2.0, height + SCREEN_MARGIN + 1.0),
         };
 
         // Act
@@ -191,7 +355,7 @@ mod tests {
         // Arrange
         let (width, height) = (100.0, 100.0);
         let space_object = DummyObject {
-            position: (width / 2.0, -1.0 * SCREEN_MARGIN - 1.0),
+            position: Vector::new(width / 2.0, -1.0 * SCREEN_MARGIN - 1.0),
         };
 
         // Act
@@ -206,7 +370,7 @@ mod tests {
         // Arrange
         let (width, height) = (100.0, 100.0);
         let space_object = DummyObject {
-            position: (0.0, 0.0),
+            position: Vector::new(0.0, 0.0),
         };
 
         // Act
@@ -221,9 +385,13 @@ mod tests {
         // Arrange
         let (x, y) = (100.0, 100.0);
 
-        let space_object = DummyObject { position: (x, y) };
+        let space_object = DummyObject {
+            position: Vector::new(x, y),
+        };
 
-        let other = DummyObject { position: (x, y) };
+        let other = DummyObject {
+            position: Vector::new(x, y),
+        };
 
         // Act
         let collision = space_object.check_collision(&other);
@@ -237,10 +405,12 @@ mod tests {
         // Arrange
         let (x, y) = (100.0, 100.0);
 
-        let space_object = DummyObject { position: (x, y) };
+        let space_object = DummyObject {
+            position: Vector::new(x, y),
+        };
 
         let other = DummyObject {
-            position: (x + MIN_DISTANCE + 1.0, y),
+            position: Vector::new(x + MIN_DISTANCE + 1.0, y),
         };
 
         // Act
@@ -255,10 +425,12 @@ mod tests {
         // Arrange
         let (x, y) = (100.0, 100.0);
 
-        let space_object = DummyObject { position: (x, y) };
+        let space_object = DummyObject {
+            position: Vector::new(x, y),
+        };
 
         let other = DummyObject {
-            position: (x, y + MIN_DISTANCE + 5.0),
+            position: Vector::new(x, y + MIN_DISTANCE + 5.0),
         };
 
         // Act
@@ -273,10 +445,12 @@ mod tests {
         // Arrange
         let (x, y) = (100.0, 100.0);
 
-        let space_object = DummyObject { position: (x, y) };
+        let space_object = DummyObject {
+            position: Vector::new(x, y),
+        };
 
         let other = DummyObject {
-            position: (x + MIN_DISTANCE + 12.0, y + MIN_DISTANCE + 5.0),
+            position: Vector::new(x + MIN_DISTANCE + 12.0, y + MIN_DISTANCE + 5.0),
         };
 
         // Act
@@ -291,10 +465,12 @@ mod tests {
         // Arrange
         let (x, y) = (100.0, 100.0);
 
-        let space_object = DummyObject { position: (x, y) };
+        let space_object = DummyObject {
+            position: Vector::new(x, y),
+        };
 
         let other = DummyObject {
-            position: (x + MIN_DISTANCE - 5.0, y),
+            position: Vector::new(x + MIN_DISTANCE - 5.0, y),
         };
 
         // Act
@@ -309,10 +485,12 @@ mod tests {
         // Arrange
         let (x, y) = (100.0, 100.0);
 
-        let space_object = DummyObject { position: (x, y) };
+        let space_object = DummyObject {
+            position: Vector::new(x, y),
+        };
 
         let other = DummyObject {
-            position: (x, y + MIN_DISTANCE - 1.0),
+            position: Vector::new(x, y + MIN_DISTANCE - 1.0),
         };
 
         // Act
@@ -1,63 +1,199 @@
-use instant::SystemTime;
-
+use crate::config::{GameConfig, HeroConfig};
 use crate::game_objects::*;
+use crate::physics::*;
 use crate::player::*;
 use crate::spaceship::*;
 
 pub struct Hero {
     spaceship: Spaceship,
-    last_attack: Option<SystemTime>,
+    config: HeroConfig,
+    play_area: PlayArea,
+    inertia: Inertia,
+    ticks: u64,
+    last_attack: Option<u64>,
+    missed_shots: usize,
+    spawn_x: f64,
+    lives: u32,
+    invulnerable_ticks: u32,
 }
 
 impl Hero {
-    pub const COLOR: Color = [1.0, 0.0, 0.0, 1.0];
-    pub const SPEED: f64 = 30.0;
-    pub const SIZE: f64 = 25.0;
+    pub const COLORS: [Color; 2] = [[1.0, 0.0, 0.0, 1.0], [0.0, 0.6, 1.0, 1.0]];
     pub const DIRECTION: Direction = Direction::UP;
-    pub const ATTACK_THRESHOLD: u64 = 200;
+    pub const SPAWN_MARGIN: f64 = 30.0;
+
+    pub fn new(screen_size: ScreenSize, config: &GameConfig) -> Hero {
+        Hero::for_player(screen_size, config, 0, 1)
+    }
+
+    pub fn for_player(
+        screen_size: ScreenSize,
+        config: &GameConfig,
+        player: usize,
+        player_count: usize,
+    ) -> Hero {
+        let spawn_x = (player + 1) as f64 / (player_count + 1) as f64;
+        let lives = if player_count > 1 {
+            config.hero.coop_lives
+        } else {
+            config.hero.lives
+        };
+        Hero::spawn(
+            screen_size,
+            config,
+            config.hero.movement,
+            lives,
+            player,
+            spawn_x,
+            Hero::DIRECTION,
+        )
+    }
+
+    pub fn for_versus(screen_size: ScreenSize, config: &GameConfig, player: usize) -> Hero {
+        let direction = if player == 0 {
+            Direction::UP
+        } else {
+            Direction::DOWN
+        };
+        Hero::spawn(
+            screen_size,
+            config,
+            config.hero.versus_movement,
+            config.hero.lives,
+            player,
+            0.5,
+            direction,
+        )
+    }
+
+    fn spawn(
+        screen_size: ScreenSize,
+        config: &GameConfig,
+        movement: MovementTuning,
+        lives: u32,
+        player: usize,
+        spawn_x: f64,
+        direction: Direction,
+    ) -> Hero {
+        let mut spaceship = Spaceship::new(
+            Hero::spawn_position(screen_size, spawn_x, direction),
+            direction,
+            screen_size,
+            Hero::COLORS[player % Hero::COLORS.len()],
+            config.hero.size,
+        );
+        spaceship.set_shot_config(config.shot);
 
-    pub fn new(screen_size: ScreenSize) -> Hero {
-        let (width, height) = screen_size;
         Hero {
+            ticks: 0,
             last_attack: None,
-            spaceship: Spaceship::new(
-                (width / 2.0, height - 30.0),
-                Hero::DIRECTION,
-                screen_size,
-                Hero::COLOR,
-                Hero::SIZE,
-            ),
+            missed_shots: 0,
+            play_area: PlayArea::from_screen_size(screen_size),
+            inertia: Inertia::new(movement),
+            spawn_x,
+            lives,
+            invulnerable_ticks: 0,
+            config: config.hero,
+            spaceship,
+        }
+    }
+
+    pub fn lives(&self) -> u32 {
+        self.lives
+    }
+
+    pub fn is_out_of_lives(&self) -> bool {
+        self.lives == 0
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable_ticks > 0
+    }
+
+    pub fn lose_life(&mut self) {
+        self.lives = self.lives.saturating_sub(1);
+        if self.is_out_of_lives() {
+            self.spaceship.destroy();
+            return;
         }
+
+        self.respawn();
+    }
+
+    pub fn respawn(&mut self) {
+        let spawn = Hero::spawn_position(
+            self.spaceship.screen_size(),
+            self.spawn_x,
+            self.spaceship.direction(),
+        );
+        self.spaceship.restore();
+        self.spaceship.move_to(self.play_area.clamp(spawn));
+        self.inertia.stop(true, true);
+        self.invulnerable_ticks = self.config.respawn_invulnerability;
+    }
+
+    pub fn play_area(&self) -> PlayArea {
+        self.play_area
     }
 
     pub fn move_to(&mut self, direction: Direction) {
-        let (width, _) = self.spaceship.screen_size();
-        let (cur_x, cur_y) = self.spaceship.position();
-        let new_x = match direction {
-            Direction::LEFT => min(cur_x - Hero::SPEED, width),
-            _ => max(cur_x + Hero::SPEED, 0.0),
-        };
-        self.spaceship.move_to((new_x, cur_y));
+        self.inertia.thrust(direction);
+    }
+
+    pub fn dash(&mut self) -> bool {
+        self.inertia.dash()
+    }
+
+    /// Drops the momentum and dash state, so that nothing carries over once
+    /// play resumes.
+    pub fn halt(&mut self) {
+        self.inertia.reset();
+    }
+
+    pub fn set_play_area(&mut self, play_area: PlayArea) {
+        self.play_area = play_area;
+        self.spaceship
+            .move_to(play_area.clamp(self.spaceship.position()));
     }
 
-    pub fn attack(&mut self) {
-        if self.should_attack(Hero::ATTACK_THRESHOLD) {
+    pub fn attack(&mut self) -> bool {
+        if self.should_attack(self.config.attack_cooldown) {
             self.spaceship.fire();
+            return true;
         }
+
+        false
     }
 
-    fn should_attack(&mut self, threshold: u64) -> bool {
-        if let Some(last_attack) = self.last_attack {
-            let duration_since_last_attack = SystemTime::now()
-                .duration_since(last_attack)
-                .expect("Failed to calculate elapsed time since last attack")
-                .as_millis();
+    pub fn take_missed_shots(&mut self) -> usize {
+        std::mem::take(&mut self.missed_shots)
+    }
 
-            if duration_since_last_attack < (threshold as u128) {
+    fn spawn_position(screen_size: ScreenSize, spawn_x: f64, direction: Direction) -> Position {
+        let (width, height) = screen_size;
+        let y = match direction {
+            Direction::DOWN => Hero::SPAWN_MARGIN,
+            _ => height - Hero::SPAWN_MARGIN,
+        };
+        Vector::new(width * spawn_x, y)
+    }
+
+    fn move_spaceship(&mut self) {
+        let new_position = self.spaceship.position() + self.inertia.step();
+        let clamped = self.play_area.clamp(new_position);
+
+        self.inertia
+            .stop(clamped.x != new_position.x, clamped.y != new_position.y);
+        self.spaceship.move_to(clamped);
+    }
+
+    fn should_attack(&mut self, threshold: u32) -> bool {
+        if let Some(last_attack) = self.last_attack {
+            if self.ticks - last_attack < u64::from(threshold) {
                 return false;
             }
         }
-        self.last_attack = Some(SystemTime::now());
+        self.last_attack = Some(self.ticks);
         true
     }
 }
@@ -72,20 +208,25 @@ impl Player for Hero {
     }
 
     fn action(&mut self) {
-        self.spaceship.update_shot_position();
+        self.invulnerable_ticks = self.invulnerable_ticks.saturating_sub(1);
+        self.ticks += 1;
+        self.move_spaceship();
+        self.missed_shots += self.spaceship.update_shot_position();
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
     use crate::spaceship::tests::*;
-    use instant::{Duration, SystemTime};
+
+    const CONFIG: GameConfig = GameConfig::DEFAULT;
 
     #[test]
     fn attack_should_fire_spaceship_shot_on_the_first_attack() {
         // Arrange
-        let mut hero = Hero::new((800.0, 600.0));
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
 
         // Act
         hero.attack();
@@ -94,10 +235,123 @@ mod tests {
         assert_eq!(spacheship_shots(hero.spaceship).len(), 1);
     }
 
+    #[test]
+    fn move_to_should_move_the_hero_in_both_axes() {
+        // Arrange
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        let start = hero.spaceship.position();
+
+        // Act
+        hero.move_to(Direction::UP);
+        hero.action();
+        hero.move_to(Direction::LEFT);
+        hero.action();
+
+        // Assert
+        let speed = MovementTuning::ARCADE.acceleration;
+        assert_eq!(
+            hero.spaceship.position(),
+            Vector::new(start.x - speed, start.y - speed)
+        );
+    }
+
+    #[test]
+    fn move_to_should_not_leave_the_play_area() {
+        // Arrange
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        hero.set_play_area(PlayArea::new(
+            Vector::new(0.0, 500.0),
+            Vector::new(800.0, 600.0),
+        ));
+
+        // Act
+        for _ in 0..10 {
+            hero.move_to(Direction::UP);
+            hero.action();
+        }
+
+        // Assert
+        assert_eq!(hero.spaceship.position().y, 500.0);
+    }
+
+    #[test]
+    fn action_should_keep_drifting_with_floaty_tuning() {
+        // Arrange
+        let mut config = CONFIG;
+        config.hero.movement = MovementTuning::FLOATY;
+        let mut hero = Hero::new((800.0, 600.0), &config);
+        let start = hero.spaceship.position();
+        hero.move_to(Direction::RIGHT);
+
+        // Act
+        hero.action();
+        hero.action();
+
+        // Assert
+        assert!(hero.spaceship.position().x > start.x + MovementTuning::FLOATY.acceleration);
+    }
+
+    #[test]
+    fn for_player_should_spread_spawn_positions_and_colors() {
+        // Arrange
+        let first = Hero::for_player((800.0, 600.0), &CONFIG, 0, 2);
+
+        // Act
+        let second = Hero::for_player((800.0, 600.0), &CONFIG, 1, 2);
+
+        // Assert
+        assert!(first.spaceship.position().x < second.spaceship.position().x);
+        assert_ne!(first.spaceship.color(), second.spaceship.color());
+    }
+
+    #[test]
+    fn for_versus_should_place_the_second_player_at_the_top_facing_down() {
+        // Arrange
+        let bottom = Hero::for_versus((800.0, 600.0), &CONFIG, 0);
+
+        // Act
+        let top = Hero::for_versus((800.0, 600.0), &CONFIG, 1);
+
+        // Assert
+        assert!(matches!(bottom.spaceship.direction(), Direction::UP));
+        assert!(matches!(top.spaceship.direction(), Direction::DOWN));
+        assert_eq!(top.spaceship.position().y, Hero::SPAWN_MARGIN);
+    }
+
+    #[test]
+    fn lose_life_should_respawn_while_lives_remain() {
+        // Arrange
+        let mut hero = Hero::for_player((800.0, 600.0), &CONFIG, 0, 2);
+        hero.spaceship.destroy();
+
+        // Act
+        hero.lose_life();
+
+        // Assert
+        assert_eq!(hero.lives(), CONFIG.hero.coop_lives - 1);
+        assert!(!hero.is_destroyed());
+        assert!(hero.is_invulnerable());
+    }
+
+    #[test]
+    fn lose_life_should_destroy_the_hero_on_the_last_life() {
+        // Arrange
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+
+        // Act
+        for _ in 0..CONFIG.hero.lives {
+            hero.lose_life();
+        }
+
+        // Assert
+        assert!(hero.is_out_of_lives());
+        assert!(hero.is_destroyed());
+    }
+
     #[test]
     fn last_attack_should_start_from_none() {
         // Arrange
-        let hero = Hero::new((800.0, 600.0));
+        let hero = Hero::new((800.0, 600.0), &CONFIG);
 
         // Assert
         assert_eq!(hero.last_attack, None);
@@ -106,7 +360,7 @@ mod tests {
     #[test]
     fn last_attack_should_be_defined_after_first_attack() {
         // Arrange
-        let mut hero = Hero::new((800.0, 600.0));
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
 
         // Act
         hero.attack();
@@ -118,11 +372,11 @@ mod tests {
     #[test]
     fn attack_should_add_shot_if_last_attack_diff_is_greater_than_shooting_threshould() {
         // Arrange
-        let threshould = Duration::from_millis(Hero::ATTACK_THRESHOLD + 1);
-        let last_attack = SystemTime::now().checked_sub(threshould);
+        let threshould = u64::from(CONFIG.hero.attack_cooldown) + 1;
 
-        let mut hero = Hero::new((800.0, 600.0));
-        hero.last_attack = last_attack;
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        hero.ticks = 100;
+        hero.last_attack = hero.ticks.checked_sub(threshould);
 
         // Act
         hero.attack();
@@ -134,11 +388,11 @@ mod tests {
     #[test]
     fn attack_should_add_not_shot_if_last_attack_diff_is_smaller_than_shooting_threshould() {
         // Arrange
-        let threshould = Duration::from_millis(1);
-        let last_attack = SystemTime::now().checked_sub(threshould);
+        let threshould = 1;
 
-        let mut hero = Hero::new((800.0, 600.0));
-        hero.last_attack = last_attack;
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        hero.ticks = 100;
+        hero.last_attack = hero.ticks.checked_sub(threshould);
 
         // Act
         hero.attack();
@@ -150,10 +404,11 @@ mod tests {
     #[test]
     fn attack_should_add_not_update_last_attack_whem_cannot_attack() {
         // Arrange
-        let threshould = Duration::from_millis(1);
-        let last_attack = SystemTime::now().checked_sub(threshould);
+        let threshould = 1;
 
-        let mut hero = Hero::new((800.0, 600.0));
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        hero.ticks = 100;
+        let last_attack = hero.ticks.checked_sub(threshould);
         hero.last_attack = last_attack;
 
         // Act
@@ -162,4 +417,69 @@ mod tests {
         // Assert
         assert_eq!(hero.last_attack, last_attack);
     }
+
+    #[test]
+    fn attack_should_add_shot_once_the_cooldown_has_elapsed() {
+        // Arrange
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        hero.attack();
+        for _ in 0..CONFIG.hero.attack_cooldown {
+            hero.action();
+        }
+
+        // Act
+        hero.attack();
+
+        // Assert
+        assert_eq!(spacheship_shots(hero.spaceship).len(), 2);
+    }
+
+    #[test]
+    fn attack_should_add_not_shot_while_cooling_down() {
+        // Arrange
+        let mut hero = Hero::new((800.0, 600.0), &CONFIG);
+        hero.attack();
+        hero.action();
+
+        // Act
+        hero.attack();
+
+        // Assert
+        assert_eq!(spacheship_shots(hero.spaceship).len(), 1);
+    }
+
+    #[test]
+    fn for_versus_should_use_the_versus_movement() {
+        // Arrange
+        let mut config = CONFIG;
+        config.hero.versus_movement = MovementTuning::FLOATY;
+        let mut hero = Hero::for_versus((800.0, 600.0), &config, 0);
+        let start = hero.spaceship.position();
+        hero.move_to(Direction::RIGHT);
+
+        // Act
+        hero.action();
+        hero.action();
+
+        // Assert
+        assert!(hero.spaceship.position().x > start.x + MovementTuning::FLOATY.acceleration);
+    }
+
+    #[test]
+    fn halt_should_drop_the_momentum() {
+        // Arrange
+        let mut config = CONFIG;
+        config.hero.movement = MovementTuning::FLOATY;
+        let mut hero = Hero::new((800.0, 600.0), &config);
+        hero.move_to(Direction::RIGHT);
+        hero.action();
+        let position = hero.spaceship.position();
+
+        // Act
+        hero.halt();
+        hero.action();
+
+        // Assert
+        assert_eq!(hero.spaceship.position(), position);
+    }
 }
@@ -0,0 +1,347 @@
+use std::cmp::Reverse;
+
+use instant::SystemTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{GameMode, GameScore};
+
+const FIELD_SEPARATOR: char = ';';
+const UNKNOWN_NAME: &str = "---";
+const SECONDS_PER_DAY: u64 = 86_400;
+
+pub trait HighScoreStorage {
+    fn load(&self) -> Option<String>;
+
+    fn save(&mut self, content: &str);
+}
+
+#[derive(Default)]
+pub struct MemoryStorage {
+    content: Option<String>,
+}
+
+impl MemoryStorage {
+    pub fn new(entries: &[HighScore]) -> MemoryStorage {
+        MemoryStorage {
+            content: Some(encode_entries(entries)),
+        }
+    }
+}
+
+impl HighScoreStorage for MemoryStorage {
+    fn load(&self) -> Option<String> {
+        self.content.clone()
+    }
+
+    fn save(&mut self, content: &str) {
+        self.content = Some(String::from(content));
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HighScore {
+    name: String,
+    score: GameScore,
+    date: u64,
+    level: u32,
+    mode: GameMode,
+}
+
+impl HighScore {
+    pub fn new(name: String, score: GameScore, level: u32, mode: GameMode) -> HighScore {
+        let date = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+
+        HighScore {
+            name,
+            score,
+            date,
+            level,
+            mode,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn score(&self) -> GameScore {
+        self.score
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn date(&self) -> String {
+        let (year, month, day) = civil_from_days(self.date / SECONDS_PER_DAY);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    fn encode(&self) -> String {
+        format!(
+            "{}{sep}{}{sep}{}{sep}{}{sep}{}",
+            self.score,
+            self.date,
+            self.level,
+            self.mode.name(),
+            self.name,
+            sep = FIELD_SEPARATOR
+        )
+    }
+
+    fn decode(line: &str) -> Option<HighScore> {
+        let mut fields = line.trim().split(FIELD_SEPARATOR);
+
+        let score = fields.next()?.parse().ok()?;
+        let date = fields.next()?.parse().ok()?;
+        let level = fields.next()?.parse().ok()?;
+        let mode = GameMode::from_name(fields.next()?)?;
+        let name = String::from(fields.next().unwrap_or(UNKNOWN_NAME));
+
+        Some(HighScore {
+            name,
+            score,
+            date,
+            level,
+            mode,
+        })
+    }
+}
+
+pub struct HighScoreTable {
+    entries: Vec<HighScore>,
+    storage: Box<dyn HighScoreStorage>,
+}
+
+impl HighScoreTable {
+    pub const MAX_ENTRIES: usize = 5;
+
+    pub fn new(storage: Box<dyn HighScoreStorage>) -> HighScoreTable {
+        let mut entries: Vec<HighScore> = storage
+            .load()
+            .map(|content| content.lines().filter_map(HighScore::decode).collect())
+            .unwrap_or_default();
+
+        entries.sort_by_key(|entry| Reverse(entry.score));
+        entries.truncate(HighScoreTable::MAX_ENTRIES);
+
+        HighScoreTable { entries, storage }
+    }
+
+    pub fn entries(&self) -> &[HighScore] {
+        &self.entries
+    }
+
+    pub fn qualifies(&self, score: GameScore) -> bool {
+        if score == 0 {
+            return false;
+        }
+
+        match self.entries.get(HighScoreTable::MAX_ENTRIES - 1) {
+            Some(lowest) => score > lowest.score,
+            None => true,
+        }
+    }
+
+    pub fn add(&mut self, entry: HighScore) -> Option<usize> {
+        if !self.qualifies(entry.score) {
+            return None;
+        }
+
+        let rank = self
+            .entries
+            .iter()
+            .position(|other| other.score < entry.score)
+            .unwrap_or(self.entries.len());
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(HighScoreTable::MAX_ENTRIES);
+        self.save();
+
+        Some(rank)
+    }
+
+    fn save(&mut self) {
+        self.storage.save(&encode_entries(&self.entries));
+    }
+}
+
+fn encode_entries(entries: &[HighScore]) -> String {
+    let content: Vec<String> = entries.iter().map(HighScore::encode).collect();
+    content.join("\n")
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: GameScore) -> HighScore {
+        HighScore {
+            name: String::from("AAA"),
+            score,
+            date: 0,
+            level: 1,
+            mode: GameMode::Solo,
+        }
+    }
+
+    fn table_with(scores: &[GameScore]) -> HighScoreTable {
+        let mut table = HighScoreTable::new(Box::new(MemoryStorage::default()));
+        for score in scores {
+            table.add(entry(*score));
+        }
+        table
+    }
+
+    #[test]
+    fn new_should_load_entries_sorted_by_score() {
+        // Arrange
+        let storage = MemoryStorage {
+            content: Some(String::from("10;0;1;solo\n30;0;1;solo\n20;0;1;solo")),
+        };
+
+        // Act
+        let table = HighScoreTable::new(Box::new(storage));
+
+        // Assert
+        let scores: Vec<GameScore> = table.entries().iter().map(|e| e.score()).collect();
+        assert_eq!(scores, vec![30, 20, 10]);
+    }
+
+    #[test]
+    fn new_should_skip_malformed_lines() {
+        // Arrange
+        let storage = MemoryStorage {
+            content: Some(String::from(
+                "10;0;1;solo\ngarbage\n20;0;x;solo\n30;0;1;unknown",
+            )),
+        };
+
+        // Act
+        let table = HighScoreTable::new(Box::new(storage));
+
+        // Assert
+        assert_eq!(table.entries().len(), 1);
+        assert_eq!(table.entries()[0].score(), 10);
+    }
+
+    #[test]
+    fn add_should_return_the_rank_of_the_new_entry() {
+        // Arrange
+        let mut table = table_with(&[50, 30, 10]);
+
+        // Act
+        let rank = table.add(entry(40));
+
+        // Assert
+        assert_eq!(rank, Some(1));
+    }
+
+    #[test]
+    fn add_should_keep_only_max_entries() {
+        // Arrange
+        let mut table = table_with(&[60, 50, 40, 30, 20]);
+
+        // Act
+        table.add(entry(35));
+
+        // Assert
+        let scores: Vec<GameScore> = table.entries().iter().map(|e| e.score()).collect();
+        assert_eq!(scores, vec![60, 50, 40, 35, 30]);
+    }
+
+    #[test]
+    fn add_should_ignore_scores_lower_than_the_full_table() {
+        // Arrange
+        let mut table = table_with(&[60, 50, 40, 30, 20]);
+
+        // Act
+        let rank = table.add(entry(10));
+
+        // Assert
+        assert_eq!(rank, None);
+        assert_eq!(table.entries().len(), HighScoreTable::MAX_ENTRIES);
+    }
+
+    #[test]
+    fn qualifies_should_return_false_for_zero() {
+        // Arrange
+        let table = table_with(&[]);
+
+        // Act
+        let qualifies = table.qualifies(0);
+
+        // Assert
+        assert!(!qualifies);
+    }
+
+    #[test]
+    fn add_should_save_entries_to_storage() {
+        // Arrange
+        let mut table = table_with(&[20]);
+
+        // Act
+        table.add(entry(30));
+
+        // Assert
+        let reloaded = HighScoreTable::new(Box::new(MemoryStorage {
+            content: table.storage.load(),
+        }));
+        assert_eq!(reloaded.entries(), table.entries());
+    }
+
+    #[test]
+    fn new_should_load_the_name_stored_with_the_score() {
+        // Arrange
+        let storage = MemoryStorage {
+            content: Some(String::from("10;0;1;solo;WEL\n20;0;1;solo")),
+        };
+
+        // Act
+        let table = HighScoreTable::new(Box::new(storage));
+
+        // Assert
+        let names: Vec<&str> = table.entries().iter().map(|e| e.name()).collect();
+        assert_eq!(names, vec![UNKNOWN_NAME, "WEL"]);
+    }
+
+    #[test]
+    fn date_should_format_timestamp_as_calendar_date() {
+        // Arrange
+        let mut high_score = entry(10);
+        high_score.date = 1_709_251_200; // 2024-03-01T00:00:00Z
+
+        // Act
+        let date = high_score.date();
+
+        // Assert
+        assert_eq!(date, "2024-03-01");
+    }
+}
@@ -0,0 +1,152 @@
+use crate::game_objects::*;
+
+const FIRST_LETTER: u8 = b'A';
+const LAST_LETTER: u8 = b'Z';
+
+#[derive(Copy, Clone, Debug)]
+pub struct InitialsEntry {
+    letters: [u8; InitialsEntry::LENGTH],
+    cursor: usize,
+}
+
+impl InitialsEntry {
+    pub const LENGTH: usize = 3;
+
+    pub fn new() -> InitialsEntry {
+        InitialsEntry {
+            letters: [FIRST_LETTER; InitialsEntry::LENGTH],
+            cursor: 0,
+        }
+    }
+
+    pub fn change(&mut self, direction: Direction) {
+        let letter = &mut self.letters[self.cursor];
+        match direction {
+            Direction::UP => {
+                *letter = if *letter == LAST_LETTER {
+                    FIRST_LETTER
+                } else {
+                    *letter + 1
+                }
+            }
+            Direction::DOWN => {
+                *letter = if *letter == FIRST_LETTER {
+                    LAST_LETTER
+                } else {
+                    *letter - 1
+                }
+            }
+            Direction::LEFT => self.cursor = self.cursor.saturating_sub(1),
+            Direction::RIGHT => self.cursor = (self.cursor + 1).min(InitialsEntry::LENGTH - 1),
+        }
+    }
+
+    pub fn confirm(&mut self) -> bool {
+        if self.cursor + 1 == InitialsEntry::LENGTH {
+            return true;
+        }
+
+        self.cursor += 1;
+        false
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn letters(&self) -> Vec<char> {
+        self.letters
+            .iter()
+            .map(|letter| char::from(*letter))
+            .collect()
+    }
+
+    pub fn name(&self) -> String {
+        self.letters().into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_should_start_with_all_letters_as_a() {
+        // Arrange
+        let entry = InitialsEntry::new();
+
+        // Act
+        let name = entry.name();
+
+        // Assert
+        assert_eq!(name, "AAA");
+    }
+
+    #[test]
+    fn change_up_should_cycle_from_z_back_to_a() {
+        // Arrange
+        let mut entry = InitialsEntry::new();
+        entry.letters[0] = LAST_LETTER;
+
+        // Act
+        entry.change(Direction::UP);
+
+        // Assert
+        assert_eq!(entry.name(), "AAA");
+    }
+
+    #[test]
+    fn change_down_should_cycle_from_a_to_z() {
+        // Arrange
+        let mut entry = InitialsEntry::new();
+
+        // Act
+        entry.change(Direction::DOWN);
+
+        // Assert
+        assert_eq!(entry.name(), "ZAA");
+    }
+
+    #[test]
+    fn change_right_should_not_move_the_cursor_past_the_last_letter() {
+        // Arrange
+        let mut entry = InitialsEntry::new();
+
+        // Act
+        for _ in 0..InitialsEntry::LENGTH + 2 {
+            entry.change(Direction::RIGHT);
+        }
+
+        // Assert
+        assert_eq!(entry.cursor(), InitialsEntry::LENGTH - 1);
+    }
+
+    #[test]
+    fn change_should_only_update_the_letter_under_the_cursor() {
+        // Arrange
+        let mut entry = InitialsEntry::new();
+        entry.change(Direction::RIGHT);
+
+        // Act
+        entry.change(Direction::UP);
+
+        // Assert
+        assert_eq!(entry.name(), "ABA");
+    }
+
+    #[test]
+    fn confirm_should_advance_the_cursor_until_the_last_letter() {
+        // Arrange
+        let mut entry = InitialsEntry::new();
+
+        // Act
+        let first = entry.confirm();
+        let second = entry.confirm();
+        let third = entry.confirm();
+
+        // Assert
+        assert!(!first);
+        assert!(!second);
+        assert!(third);
+    }
+}
@@ -1,30 +1,112 @@
-use rand::{thread_rng, Rng};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
 
+pub use crate::autopilot::Autopilot;
+pub use crate::bindings::{Binding, Control, KeyBindings, Rebinding};
+pub use crate::config::{
+    ConfigError, Difficulty, EnemyConfig, GameConfig, HeroConfig, ScoreConfig, ShotConfig,
+};
 use crate::enemy::*;
-use crate::game_objects::*;
-pub use crate::game_objects::{Color, Direction, GameText, ScreenSize, SpaceObject};
+pub use crate::env::{Action, Env, Observation, StepInfo};
+pub use crate::game_objects::{
+    Color, Direction, GameText, PlayArea, Position, ScreenSize, SpaceObject, Vector, Velocity,
+};
 use crate::hero::*;
+pub use crate::high_score::{HighScore, HighScoreStorage, HighScoreTable, MemoryStorage};
+use crate::initials::*;
+pub use crate::physics::MovementTuning;
 use crate::player::*;
+pub use crate::render::{Anchor, Layer, Primitive, RenderCommand, RenderFrame};
+pub use crate::replay::{Replay, ReplayEvent, ReplayInput};
+pub use crate::score::PlayerStats;
+use crate::score::*;
 use crate::stars::*;
+pub use crate::svg::frame_to_svg;
+pub use crate::viewport::{Rect, Viewport};
+use crate::waves::WaveProgress;
+pub use crate::waves::{Campaign, Clear, EnemyKind, Entry, Formation, Spawn, Wave};
 
+mod autopilot;
+mod bindings;
+mod config;
 mod enemy;
+mod env;
 mod game_objects;
 mod hero;
+mod high_score;
+mod initials;
+mod physics;
 mod player;
+mod render;
+mod replay;
+mod score;
 mod shot;
 mod spaceship;
 mod stars;
+mod svg;
+mod viewport;
+mod waves;
 
-type GameScore = u32;
+pub type GameScore = u32;
+pub type PlayerId = usize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GameMode {
+    Solo,
+    Coop,
+    Versus,
+}
+
+impl GameMode {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            GameMode::Solo => "solo",
+            GameMode::Coop => "coop",
+            GameMode::Versus => "versus",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<GameMode> {
+        match name {
+            "solo" => Some(GameMode::Solo),
+            "coop" => Some(GameMode::Coop),
+            "versus" => Some(GameMode::Versus),
+            _ => None,
+        }
+    }
+
+    fn index(&self) -> usize {
+        match *self {
+            GameMode::Solo => 0,
+            GameMode::Coop => 1,
+            GameMode::Versus => 2,
+        }
+    }
+
+    pub fn player_count(&self) -> usize {
+        match *self {
+            GameMode::Solo => 1,
+            GameMode::Coop | GameMode::Versus => 2,
+        }
+    }
+
+    pub fn is_timed(&self) -> bool {
+        *self == GameMode::Versus
+    }
+}
 
 #[derive(Copy, Clone)]
 enum Scene {
     StartGame,
+    Attract,
     InGame,
+    EnterInitials(InitialsEntry),
     GameOver,
 }
 
 impl Scene {
+    #[allow(clippy::match_like_matches_macro)]
     fn is_in_game(&self) -> bool {
         match *self {
             Scene::InGame => true,
@@ -32,6 +114,19 @@ impl Scene {
         }
     }
 
+    #[allow(clippy::match_like_matches_macro)]
+    fn is_attract(&self) -> bool {
+        match *self {
+            Scene::Attract => true,
+            _ => false,
+        }
+    }
+
+    fn is_playing(&self) -> bool {
+        self.is_in_game() || self.is_attract()
+    }
+
+    #[allow(clippy::match_like_matches_macro)]
     fn is_game_over(&self) -> bool {
         match *self {
             Scene::GameOver => true,
@@ -41,107 +136,502 @@ impl Scene {
 }
 
 pub struct Game {
+    config: GameConfig,
     screen_size: ScreenSize,
-    hero: Hero,
+    heroes: Vec<Hero>,
     enemies: Vec<Enemy>,
     background_stars: Vec<Star>,
-    score: GameScore,
+    random: StdRng,
+    scores: Vec<ScoreBoard>,
+    level: u32,
+    mode: GameMode,
+    campaigns: [Campaign; 3],
+    waves: WaveProgress,
+    high_scores: HighScoreTable,
     scene: Scene,
+    scene_ticks: u32,
+    remaining_ticks: u32,
     count: u64,
+    paused: bool,
 }
 
 impl Game {
-    pub const STAR_COUNT: i32 = 100;
+    pub const LOGICAL_WIDTH: f64 = 800.0;
+    pub const LOGICAL_HEIGHT: f64 = 600.0;
     pub const TEXT_COLOR: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
     pub const TEXT_SIZE: u32 = 32;
-    pub const SCORE_TEXT_POSITION: Position = (30.0, 30.0);
+    pub const PLAYER_ONE: PlayerId = 0;
+    pub const PLAYER_TWO: PlayerId = 1;
+    pub const SCORE_TEXT_POSITION: Position = Vector::new(30.0, 30.0);
+    pub const LIVES_TEXT_POSITION: Position = Vector::new(30.0, 62.0);
+    pub const PLAYER_HUD_SPACING: f64 = 400.0;
+    pub const TICKS_PER_SECOND: u32 = 40;
+    pub const TIMER_TEXT_LEFT_PADDING: f64 = 40.0;
+    pub const TIMER_TEXT_TOP: f64 = 30.0;
     pub const GAME_OVER_POSITION_LEFT_PADDING: f64 = 100.0;
+    pub const GAME_OVER_POSITION_TOP_PADDING: f64 = 140.0;
+    pub const BREAKDOWN_LEFT_PADDING: f64 = 150.0;
+    pub const BREAKDOWN_TOP_PADDING: f64 = 100.0;
+    pub const BREAKDOWN_COLUMN_SPACING: f64 = 320.0;
+    pub const COMBO_TEXT_POSITION: Position = Vector::new(30.0, 88.0);
+    pub const COMBO_BAR_LENGTH: u32 = 10;
     pub const FINAL_SCORE_LEFT_PADDING: f64 = 80.0;
     pub const FINAL_SCORE_TOP_PADDING: f64 = 32.0;
     pub const START_GAME_TEXT_PADDING: f64 = 200.0;
-    pub const ENEMY_FREQUENCY: f64 = 0.015;
-    pub const POINTS: u32 = 10;
+    pub const COOP_HINT_LEFT_PADDING: f64 = 190.0;
+    pub const COOP_HINT_TOP_PADDING: f64 = 40.0;
+    pub const INITIAL_LEVEL: u32 = 1;
+    pub const HIGH_SCORE_TEXT_SIZE: u32 = 20;
+    pub const HIGH_SCORE_LINE_HEIGHT: f64 = 26.0;
+    pub const HIGH_SCORE_LEFT_PADDING: f64 = 170.0;
+    pub const HIGH_SCORE_TOP_PADDING: f64 = 90.0;
+    pub const HIGHLIGHT_TEXT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+    pub const NEW_HIGH_SCORE_LEFT_PADDING: f64 = 130.0;
+    pub const NEW_HIGH_SCORE_TOP_PADDING: f64 = 80.0;
+    pub const INITIALS_LEFT_PADDING: f64 = 50.0;
+    pub const INITIALS_TOP_PADDING: f64 = 50.0;
+    pub const INITIALS_SPACING: f64 = 40.0;
+    pub const INITIALS_HINT_LEFT_PADDING: f64 = 170.0;
+    pub const INITIALS_HINT_TOP_PADDING: f64 = 100.0;
+    pub const PAUSED_TEXT_LEFT_PADDING: f64 = 60.0;
+    /// Keeps the fire key, still held when the game ends, from confirming
+    /// the initials.
+    pub const INITIALS_INPUT_DELAY: u32 = Game::TICKS_PER_SECOND;
 
-    pub fn new(width: f64, height: f64) -> Game {
-        let screen_size = (width, height);
+    pub fn new(
+        width: f64,
+        height: f64,
+        high_score_storage: Box<dyn HighScoreStorage>,
+    ) -> Result<Game, ConfigError> {
+        let config = GameConfig {
+            width,
+            height,
+            ..GameConfig::DEFAULT
+        };
+        Game::with_config(config, high_score_storage)
+    }
 
-        let stars = (0..Game::STAR_COUNT)
-            .map(|_| -> Star { Star::new(screen_size) })
-            .collect();
+    pub fn with_config(
+        config: GameConfig,
+        high_score_storage: Box<dyn HighScoreStorage>,
+    ) -> Result<Game, ConfigError> {
+        config.validate()?;
+        Ok(Game::build(config, high_score_storage))
+    }
+
+    fn build(config: GameConfig, high_score_storage: Box<dyn HighScoreStorage>) -> Game {
+        let screen_size = (config.width, config.height);
+
+        let mut random = StdRng::from_entropy();
+        let stars = Game::scatter_stars(&config, &mut random);
+
+        let campaigns = [Campaign::solo(), Campaign::solo(), Campaign::versus()];
+        let waves = WaveProgress::new(&campaigns[GameMode::Solo.index()]);
 
         Game {
+            config,
             screen_size,
-            hero: Hero::new(screen_size),
+            heroes: vec![Hero::new(screen_size, &config)],
             enemies: Vec::new(),
             background_stars: stars,
-            score: 0,
+            random,
+            scores: vec![ScoreBoard::new(config.score)],
+            level: Game::INITIAL_LEVEL,
+            mode: GameMode::Solo,
+            campaigns,
+            waves,
+            high_scores: HighScoreTable::new(high_score_storage),
             scene: Scene::StartGame,
+            scene_ticks: 0,
+            remaining_ticks: 0,
             count: 0,
+            paused: false,
         }
     }
 
+    pub fn seed_random(&mut self, seed: u64) {
+        self.random = StdRng::seed_from_u64(seed);
+        self.background_stars = Game::scatter_stars(&self.config, &mut self.random);
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Replaces the waves played in `mode`, from the next game on.
+    pub fn set_campaign(&mut self, mode: GameMode, campaign: Campaign) -> Result<(), ConfigError> {
+        campaign.validate()?;
+        self.campaigns[mode.index()] = campaign;
+        Ok(())
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
     pub fn screen_size(&self) -> ScreenSize {
         self.screen_size
     }
 
+    pub fn set_play_area(&mut self, play_area: PlayArea) {
+        self.heroes
+            .iter_mut()
+            .for_each(|hero| hero.set_play_area(play_area));
+    }
+
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn lives(&self, player: PlayerId) -> u32 {
+        self.heroes.get(player).map_or(0, |hero| hero.lives())
+    }
+
+    pub fn player_stats(&self, player: PlayerId) -> Option<PlayerStats> {
+        self.scores.get(player).map(|score| score.stats())
+    }
+
+    pub fn hero_position(&self, player: PlayerId) -> Option<Position> {
+        self.heroes
+            .get(player)
+            .filter(|hero| !hero.is_destroyed())
+            .map(|hero| hero.spaceship().position())
+    }
+
+    pub fn enemy_positions(&self) -> Vec<Position> {
+        self.enemies
+            .iter()
+            .filter(|enemy| !enemy.is_destroyed())
+            .map(|enemy| enemy.spaceship().position())
+            .collect()
+    }
+
+    pub fn enemy_shot_positions(&self) -> Vec<Position> {
+        self.enemies
+            .iter()
+            .flat_map(|enemy| enemy.spaceship().shot_positions())
+            .collect()
+    }
+
+    pub fn hero_direction(&self, player: PlayerId) -> Option<Direction> {
+        self.heroes
+            .get(player)
+            .map(|hero| hero.spaceship().direction())
+    }
+
+    pub fn target_positions(&self, player: PlayerId) -> Vec<Position> {
+        let mut targets = self.enemy_positions();
+        if self.mode == GameMode::Versus {
+            targets.extend(
+                (0..self.heroes.len())
+                    .filter(|&other| other != player)
+                    .filter_map(|other| self.hero_position(other)),
+            );
+        }
+        targets
+    }
+
+    pub fn shots_against(&self, player: PlayerId) -> Vec<Position> {
+        let mut shots = self.enemy_shot_positions();
+        if self.mode == GameMode::Versus {
+            shots.extend(
+                self.heroes
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != player)
+                    .flat_map(|(_, hero)| hero.spaceship().shot_positions()),
+            );
+        }
+        shots
+    }
+
+    pub fn is_in_game(&self) -> bool {
+        self.scene.is_in_game()
+    }
+
+    pub fn is_demo(&self) -> bool {
+        self.scene.is_attract()
+    }
+
+    pub fn is_entering_initials(&self) -> bool {
+        matches!(self.scene, Scene::EnterInitials(_))
+    }
+
+    pub fn is_over(&self) -> bool {
+        matches!(self.scene, Scene::EnterInitials(_) | Scene::GameOver)
+    }
+
+    pub fn high_scores(&self) -> &[HighScore] {
+        self.high_scores.entries()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pauses or resumes a game in progress. The attract mode and the other
+    /// screens cannot be paused.
+    pub fn toggle_pause(&mut self) {
+        match self.scene {
+            Scene::InGame => {
+                self.paused = !self.paused;
+                self.heroes.iter_mut().for_each(|hero| hero.halt());
+            }
+            Scene::Attract => self.end_attract_mode(),
+            _ => {}
+        }
+    }
+
     pub fn next_turn(&mut self) {
+        if self.paused {
+            return;
+        }
+
         self.inc_counter();
         self.background_stars_movement();
+        self.update_attract_mode();
 
-        if self.scene.is_in_game() {
+        if self.scene.is_playing() {
             self.generate_enemies();
             self.enemies_action();
             self.player_action();
 
+            self.scores.iter_mut().for_each(|score| score.tick());
             self.update_score();
+            self.update_timer();
         }
     }
 
-    pub fn space_objects(&self) -> Vec<Box<&dyn SpaceObject>> {
-        let mut objects: Vec<Box<&dyn SpaceObject>> = vec![];
+    pub fn space_objects(&self) -> Vec<&dyn SpaceObject> {
+        self.layered_objects()
+            .into_iter()
+            .map(|(_, object)| object)
+            .collect()
+    }
+
+    fn layered_objects(&self) -> Vec<(Layer, &dyn SpaceObject)> {
+        let mut objects: Vec<(Layer, &dyn SpaceObject)> = vec![];
 
         for star in &self.background_stars {
-            objects.push(Box::new(star));
+            objects.push((Layer::Background, star));
         }
 
-        if self.scene.is_in_game() {
+        if self.scene.is_playing() {
             for enemy in &self.enemies {
-                objects.append(&mut enemy.spaceship().as_game_objects());
+                for object in enemy.spaceship().as_game_objects() {
+                    objects.push((Layer::Enemies, object));
+                }
             }
 
-            if !self.hero.is_destroyed() {
-                objects.append(&mut self.hero.spaceship().as_game_objects());
+            let blink = self.count % 8 < 4;
+            for hero in &self.heroes {
+                if !hero.is_destroyed() && (!hero.is_invulnerable() || blink) {
+                    for object in hero.spaceship().as_game_objects() {
+                        objects.push((Layer::Heroes, object));
+                    }
+                }
             }
         }
 
         objects
     }
 
+    pub fn render_frame(&self) -> RenderFrame {
+        let mut frame = RenderFrame::new(self.screen_size);
+
+        for (layer, object) in self.layered_objects() {
+            frame.push_object(layer, object);
+        }
+
+        for text in self.texts() {
+            frame.push_text(Layer::Hud, &text);
+        }
+
+        frame
+    }
+
     pub fn texts(&self) -> Vec<GameText> {
         match self.scene {
             Scene::InGame => self.in_game_text(),
             Scene::StartGame => self.start_game_text(),
+            Scene::Attract => self.attract_text(),
+            Scene::EnterInitials(entry) => self.enter_initials_text(&entry),
             Scene::GameOver => self.game_over_text(),
         }
     }
 
-    pub fn move_player(&mut self, direction: Direction) {
-        self.hero.move_to(direction);
+    pub fn move_player(&mut self, player: PlayerId, direction: Direction) {
+        match self.scene {
+            Scene::EnterInitials(_) if self.scene_ticks < Game::INITIALS_INPUT_DELAY => {}
+            Scene::EnterInitials(ref mut entry) => entry.change(direction),
+            Scene::StartGame | Scene::Attract => self.key_pressed(),
+            _ if self.paused => {}
+            _ => self.steer_hero(player, direction),
+        }
+    }
+
+    pub fn fire_player_attack(&mut self, player: PlayerId) {
+        match self.scene {
+            Scene::EnterInitials(_) => self.confirm_initial(),
+            Scene::StartGame | Scene::Attract => self.key_pressed(),
+            _ if self.paused => {}
+            _ => self.fire_hero(player),
+        }
+    }
+
+    /// Confirms the letter being entered, or starts a game from the title
+    /// screen and the attract mode.
+    pub fn confirm(&mut self) {
+        match self.scene {
+            Scene::EnterInitials(_) => self.confirm_initial(),
+            _ => self.key_pressed(),
+        }
+    }
+
+    fn confirm_initial(&mut self) {
+        if self.scene_ticks < Game::INITIALS_INPUT_DELAY {
+            return;
+        }
+        if let Scene::EnterInitials(ref mut entry) = self.scene {
+            if entry.confirm() {
+                let name = entry.name();
+                self.record_high_score(name);
+            }
+        }
+    }
+
+    pub fn dash_player(&mut self, player: PlayerId) {
+        match self.scene {
+            Scene::StartGame | Scene::Attract => self.key_pressed(),
+            _ if self.scene.is_in_game() && !self.paused => self.dash_hero(player),
+            _ => {}
+        }
     }
 
-    pub fn fire_player_attack(&mut self) {
-        self.hero.attack();
+    fn steer_hero(&mut self, player: PlayerId, direction: Direction) {
+        if let Some(hero) = self.active_hero(player) {
+            hero.move_to(direction);
+        }
+    }
+
+    fn fire_hero(&mut self, player: PlayerId) {
+        let fired = self.active_hero(player).is_some_and(|hero| hero.attack());
+        if fired {
+            self.scores[player].shot_fired();
+        }
+    }
+
+    fn dash_hero(&mut self, player: PlayerId) {
+        if let Some(hero) = self.active_hero(player) {
+            hero.dash();
+        }
     }
 
     pub fn key_pressed(&mut self) {
+        self.start(GameMode::Solo);
+    }
+
+    pub fn start(&mut self, mode: GameMode) {
+        if let Scene::StartGame | Scene::Attract = self.scene {
+            self.reset_play(mode);
+            self.scene = Scene::InGame;
+        }
+    }
+
+    fn reset_play(&mut self, mode: GameMode) {
+        self.mode = mode;
+        self.paused = false;
+        self.heroes = (0..mode.player_count())
+            .map(|player| self.new_hero(player, mode.player_count()))
+            .collect();
+        self.scores = vec![ScoreBoard::new(self.config.score); mode.player_count()];
+        self.enemies.clear();
+        let skipped = self.config.starting_level - Game::INITIAL_LEVEL;
+        self.waves = WaveProgress::starting_at(&self.campaigns[mode.index()], skipped);
+        self.level = self.config.starting_level;
+        self.remaining_ticks = if mode.is_timed() {
+            self.config.versus_time_limit
+        } else {
+            0
+        };
+    }
+
+    fn update_attract_mode(&mut self) {
+        self.scene_ticks = self.scene_ticks.saturating_add(1);
+
         match self.scene {
-            Scene::StartGame => {
-                self.scene = Scene::InGame;
+            Scene::StartGame if self.scene_ticks >= self.config.attract_delay => {
+                self.reset_play(GameMode::Solo);
+                self.enter_scene(Scene::Attract);
+            }
+            Scene::Attract if self.scene_ticks >= self.config.attract_duration => {
+                self.end_attract_mode();
             }
+            Scene::Attract => self.fly_demo(),
             _ => {}
         }
     }
 
+    fn fly_demo(&mut self) {
+        for input in Autopilot::new().inputs(self, Game::PLAYER_ONE) {
+            match input {
+                ReplayInput::Move(player, direction) => self.steer_hero(player, direction),
+                ReplayInput::Fire(player) => self.fire_hero(player),
+                ReplayInput::Dash(player) => self.dash_hero(player),
+                _ => {}
+            }
+        }
+    }
+
+    fn end_attract_mode(&mut self) {
+        self.enemies.clear();
+        self.enter_scene(Scene::StartGame);
+    }
+
+    fn enter_scene(&mut self, scene: Scene) {
+        self.scene = scene;
+        self.scene_ticks = 0;
+    }
+
+    fn new_hero(&self, player: PlayerId, player_count: usize) -> Hero {
+        let template = &self.heroes[Game::PLAYER_ONE];
+
+        let mut hero = match self.mode {
+            GameMode::Versus => Hero::for_versus(self.screen_size, &self.config, player),
+            _ => Hero::for_player(self.screen_size, &self.config, player, player_count),
+        };
+        hero.set_play_area(match self.mode {
+            GameMode::Versus => self.versus_half(player),
+            _ => template.play_area(),
+        });
+        hero
+    }
+
+    fn versus_half(&self, player: PlayerId) -> PlayArea {
+        let (width, height) = self.screen_size;
+        if player == Game::PLAYER_ONE {
+            PlayArea::new(Vector::new(0.0, height / 2.0), Vector::new(width, height))
+        } else {
+            PlayArea::new(Vector::ZERO, Vector::new(width, height / 2.0))
+        }
+    }
+
+    fn active_hero(&mut self, player: PlayerId) -> Option<&mut Hero> {
+        self.heroes
+            .get_mut(player)
+            .filter(|hero| !hero.is_out_of_lives())
+    }
+
+    fn total_score(&self) -> GameScore {
+        self.scores.iter().map(|score| score.total()).sum()
+    }
+
+    fn scatter_stars(config: &GameConfig, random: &mut StdRng) -> Vec<Star> {
+        (0..config.star_count)
+            .map(|_| Star::new((config.width, config.height), random))
+            .collect()
+    }
+
     fn background_stars_movement(&mut self) {
         self.background_stars
             .iter_mut()
@@ -149,23 +639,61 @@ impl Game {
     }
 
     fn generate_enemies(&mut self) {
-        let mut random = thread_rng();
-
         self.enemies
             .retain(|enemy| enemy.is_visible(self.screen_size) && !enemy.is_destroyed());
 
-        if random.gen_bool(Game::ENEMY_FREQUENCY) {
-            let enemy = Enemy::new(self.screen_size);
-            self.enemies.push(enemy);
+        let kills = self.scores.iter().map(|score| score.kills()).sum();
+        let arrivals = self.waves.update(
+            &self.campaigns[self.mode.index()],
+            kills,
+            !self.enemies.is_empty(),
+        );
+        for arrival in arrivals {
+            self.enemies.push(Enemy::enter(
+                self.screen_size,
+                &self.config,
+                &arrival,
+                &mut self.random,
+            ));
         }
+
+        self.level = Game::INITIAL_LEVEL + self.waves.waves_cleared();
     }
 
     fn enemies_action(&mut self) {
-        self.enemies.iter_mut().for_each(|enemy| enemy.action())
+        let targets: Vec<Position> = self
+            .heroes
+            .iter()
+            .filter(|hero| self.mode != GameMode::Versus && !hero.is_destroyed())
+            .map(|hero| hero.spaceship().position())
+            .collect();
+
+        self.enemies.iter_mut().for_each(|enemy| {
+            let position = enemy.spaceship().position();
+            let nearest = targets.iter().min_by(|a, b| {
+                a.distance(position)
+                    .partial_cmp(&b.distance(position))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            if let Some(target) = nearest {
+                enemy.aim_at(*target);
+            }
+            enemy.action()
+        })
     }
 
     fn player_action(&mut self) {
-        self.hero.action();
+        for (hero, score) in self.heroes.iter_mut().zip(self.scores.iter_mut()) {
+            if hero.is_out_of_lives() {
+                continue;
+            }
+
+            hero.action();
+
+            if hero.take_missed_shots() > 0 {
+                score.break_chain();
+            }
+        }
     }
 
     fn update_score(&mut self) {
@@ -173,71 +701,402 @@ impl Game {
             return;
         }
 
+        if self.mode == GameMode::Versus {
+            self.update_versus_score();
+            return;
+        }
+
         for enemy in self.enemies.iter_mut() {
-            if enemy.hits(&mut self.hero) {
-                self.scene = Scene::GameOver;
-                return;
+            for (hero, score) in self.heroes.iter_mut().zip(self.scores.iter_mut()) {
+                if hero.is_out_of_lives() {
+                    continue;
+                }
+
+                if !hero.is_invulnerable() && enemy.hits(hero) {
+                    score.break_chain();
+                    hero.lose_life();
+                    continue;
+                }
+
+                if hero.hits(enemy) {
+                    hero.spaceship_mut().remove_shots_hitting(enemy.spaceship());
+                    let hero_position = hero.spaceship().position();
+                    let enemy_position = enemy.spaceship().position();
+                    score.kill(hero_position.distance(enemy_position));
+                }
             }
+        }
+
+        if self.heroes.iter().all(|hero| hero.is_out_of_lives()) {
+            self.game_over();
+        }
+    }
 
-            if self.hero.hits(enemy) {
-                self.score += Game::POINTS;
+    fn update_versus_score(&mut self) {
+        for enemy in self.enemies.iter_mut() {
+            for (hero, score) in self.heroes.iter_mut().zip(self.scores.iter_mut()) {
+                if !hero.is_invulnerable() && enemy.hits(hero) {
+                    score.break_chain();
+                    hero.respawn();
+                }
+                if hero.hits(enemy) {
+                    hero.spaceship_mut().remove_shots_hitting(enemy.spaceship());
+                }
             }
         }
+
+        let (first, second) = self.heroes.split_at_mut(Game::PLAYER_TWO);
+        let bottom = &mut first[Game::PLAYER_ONE];
+        let top = &mut second[0];
+        let distance = bottom
+            .spaceship()
+            .position()
+            .distance(top.spaceship().position());
+
+        if !top.is_invulnerable() && bottom.hits(top) {
+            bottom.spaceship_mut().remove_shots_hitting(top.spaceship());
+            self.scores[Game::PLAYER_ONE].kill(distance);
+            top.respawn();
+        }
+        if !bottom.is_invulnerable() && top.hits(bottom) {
+            top.spaceship_mut().remove_shots_hitting(bottom.spaceship());
+            self.scores[Game::PLAYER_TWO].kill(distance);
+            bottom.respawn();
+        }
+    }
+
+    fn update_timer(&mut self) {
+        if !self.mode.is_timed() || !self.scene.is_playing() {
+            return;
+        }
+
+        self.remaining_ticks = self.remaining_ticks.saturating_sub(1);
+        if self.remaining_ticks == 0 {
+            self.scores.iter_mut().for_each(|score| score.finish());
+            self.scene = Scene::GameOver;
+        }
+    }
+
+    fn versus_result(&self) -> String {
+        let bottom_hits = self.scores[Game::PLAYER_ONE].kills();
+        let top_hits = self.scores[Game::PLAYER_TWO].kills();
+
+        let winner = match bottom_hits.cmp(&top_hits) {
+            std::cmp::Ordering::Greater => "P1 Wins!",
+            std::cmp::Ordering::Less => "P2 Wins!",
+            std::cmp::Ordering::Equal => "Draw!",
+        };
+        format!("{} {} - {}", winner, bottom_hits, top_hits)
+    }
+
+    fn game_over(&mut self) {
+        if let Scene::Attract = self.scene {
+            self.end_attract_mode();
+            return;
+        }
+
+        self.scores.iter_mut().for_each(|score| score.finish());
+        self.heroes.iter_mut().for_each(|hero| hero.halt());
+        let scene = if self.high_scores.qualifies(self.total_score()) {
+            Scene::EnterInitials(InitialsEntry::new())
+        } else {
+            Scene::GameOver
+        };
+        self.enter_scene(scene);
+    }
+
+    fn record_high_score(&mut self, name: String) {
+        self.high_scores.add(HighScore::new(
+            name,
+            self.total_score(),
+            self.level,
+            self.mode,
+        ));
+        self.scene = Scene::GameOver;
+    }
+
+    fn enter_initials_text(&self, entry: &InitialsEntry) -> Vec<GameText> {
+        let (screen_x, screen_y) = self.screen_size;
+        let title_pos_y = screen_y / 2.0 - Game::NEW_HIGH_SCORE_TOP_PADDING;
+
+        let mut texts = vec![
+            GameText::new(
+                String::from("New High Score!"),
+                Game::TEXT_COLOR,
+                Game::TEXT_SIZE,
+                Vector::new(
+                    screen_x / 2.0 - Game::NEW_HIGH_SCORE_LEFT_PADDING,
+                    title_pos_y,
+                ),
+            ),
+            GameText::new(
+                format!("Score: {}", self.total_score()),
+                Game::TEXT_COLOR,
+                Game::TEXT_SIZE,
+                Vector::new(
+                    screen_x / 2.0 - Game::FINAL_SCORE_LEFT_PADDING,
+                    title_pos_y + Game::FINAL_SCORE_TOP_PADDING,
+                ),
+            ),
+            GameText::new(
+                String::from("Up/Down: change letter   Fire: confirm"),
+                Game::TEXT_COLOR,
+                Game::HIGH_SCORE_TEXT_SIZE,
+                Vector::new(
+                    screen_x / 2.0 - Game::INITIALS_HINT_LEFT_PADDING,
+                    screen_y / 2.0 + Game::INITIALS_HINT_TOP_PADDING,
+                ),
+            ),
+        ];
+
+        let show_cursor = self.count % 20 < 15;
+        for (i, letter) in entry.letters().into_iter().enumerate() {
+            let color = if i == entry.cursor() && show_cursor {
+                Game::HIGHLIGHT_TEXT_COLOR
+            } else {
+                Game::TEXT_COLOR
+            };
+
+            texts.push(GameText::new(
+                letter.to_string(),
+                color,
+                Game::TEXT_SIZE,
+                Vector::new(
+                    screen_x / 2.0 - Game::INITIALS_LEFT_PADDING
+                        + i as f64 * Game::INITIALS_SPACING,
+                    screen_y / 2.0 + Game::INITIALS_TOP_PADDING,
+                ),
+            ));
+        }
+
+        texts
     }
 
     fn game_over_text(&self) -> Vec<GameText> {
         let (screen_x, screen_y) = self.screen_size;
         let game_over_pos_x = screen_x / 2.0 - Game::GAME_OVER_POSITION_LEFT_PADDING;
-        let game_over_pos_y = screen_y / 2.0;
+        let game_over_pos_y = screen_y / 2.0 - Game::GAME_OVER_POSITION_TOP_PADDING;
 
         let final_score_pos_x = screen_x / 2.0 - Game::FINAL_SCORE_LEFT_PADDING;
         let final_score_pos_y = screen_y / 2.0 + Game::FINAL_SCORE_TOP_PADDING;
 
-        vec![
+        let mut texts = vec![
             GameText::new(
                 String::from("Game Over!"),
                 Game::TEXT_COLOR,
                 Game::TEXT_SIZE,
-                (game_over_pos_x, game_over_pos_y),
+                Vector::new(game_over_pos_x, game_over_pos_y),
             ),
             GameText::new(
-                format!("Score: {}", self.score),
+                match self.mode {
+                    GameMode::Versus => self.versus_result(),
+                    _ => format!("Score: {}", self.total_score()),
+                },
                 Game::TEXT_COLOR,
                 Game::TEXT_SIZE,
-                (final_score_pos_x, final_score_pos_y),
+                Vector::new(final_score_pos_x, final_score_pos_y),
             ),
-        ]
+        ];
+        texts.append(&mut self.score_breakdown_text());
+        texts.append(&mut self.high_score_text());
+        texts
+    }
+
+    fn score_breakdown_text(&self) -> Vec<GameText> {
+        let (screen_x, screen_y) = self.screen_size;
+        let top_y = screen_y / 2.0 - Game::BREAKDOWN_TOP_PADDING;
+        let center = (self.scores.len() as f64 - 1.0) / 2.0;
+
+        let mut texts = Vec::new();
+        for (player, score) in self.scores.iter().enumerate() {
+            let pos_x = screen_x / 2.0 - Game::BREAKDOWN_LEFT_PADDING
+                + (player as f64 - center) * Game::BREAKDOWN_COLUMN_SPACING;
+
+            let lines = [
+                format!(
+                    "{}Kills: {}  +{}",
+                    self.player_label(player),
+                    score.kills(),
+                    score.kill_points()
+                ),
+                format!("Combo bonus: +{}", score.combo_bonus()),
+                format!("Close kills: +{}", score.close_kill_bonus()),
+                format!(
+                    "Accuracy {}%: +{}",
+                    score.accuracy(),
+                    score.accuracy_bonus()
+                ),
+            ];
+
+            texts.extend(lines.into_iter().enumerate().map(|(i, line)| {
+                GameText::new(
+                    line,
+                    Game::TEXT_COLOR,
+                    Game::HIGH_SCORE_TEXT_SIZE,
+                    Vector::new(pos_x, top_y + i as f64 * Game::HIGH_SCORE_LINE_HEIGHT),
+                )
+            }));
+        }
+
+        texts
     }
 
     fn in_game_text(&self) -> Vec<GameText> {
-        vec![GameText::new(
-            format!("Score: {}", self.score),
-            Game::TEXT_COLOR,
-            Game::TEXT_SIZE,
-            Game::SCORE_TEXT_POSITION,
-        )]
+        let mut texts = Vec::new();
+
+        for (player, (hero, score)) in self.heroes.iter().zip(self.scores.iter()).enumerate() {
+            let offset = Vector::new(player as f64 * Game::PLAYER_HUD_SPACING, 0.0);
+            let color = hero.spaceship().color();
+
+            texts.push(GameText::new(
+                format!("{}Score: {}", self.player_label(player), score.total()),
+                Game::TEXT_COLOR,
+                Game::TEXT_SIZE,
+                Game::SCORE_TEXT_POSITION + offset,
+            ));
+            texts.push(GameText::new(
+                match self.mode {
+                    GameMode::Versus => format!("Hits: {}", score.kills()),
+                    _ => format!("Lives: {}", hero.lives()),
+                },
+                color,
+                Game::HIGH_SCORE_TEXT_SIZE,
+                Game::LIVES_TEXT_POSITION + offset,
+            ));
+
+            let chain_ticks = score.chain_ticks();
+            if chain_ticks > 0 {
+                let bar_length =
+                    (chain_ticks * Game::COMBO_BAR_LENGTH).div_ceil(score.combo_window());
+                texts.push(GameText::new(
+                    format!(
+                        "x{} {}",
+                        score.multiplier(),
+                        "=".repeat(bar_length as usize)
+                    ),
+                    Game::TEXT_COLOR,
+                    Game::HIGH_SCORE_TEXT_SIZE,
+                    Game::COMBO_TEXT_POSITION + offset,
+                ));
+            }
+        }
+
+        if self.mode.is_timed() {
+            let (screen_x, _) = self.screen_size;
+            texts.push(GameText::new(
+                format!("{}", self.remaining_ticks.div_ceil(Game::TICKS_PER_SECOND)),
+                Game::TEXT_COLOR,
+                Game::TEXT_SIZE,
+                Vector::new(
+                    screen_x / 2.0 - Game::TIMER_TEXT_LEFT_PADDING,
+                    Game::TIMER_TEXT_TOP,
+                ),
+            ));
+        }
+
+        if self.paused {
+            let (screen_x, screen_y) = self.screen_size;
+            texts.push(GameText::new(
+                String::from("Paused"),
+                Game::HIGHLIGHT_TEXT_COLOR,
+                Game::TEXT_SIZE,
+                Vector::new(
+                    screen_x / 2.0 - Game::PAUSED_TEXT_LEFT_PADDING,
+                    screen_y / 2.0,
+                ),
+            ));
+        }
+
+        texts
+    }
+
+    fn player_label(&self, player: PlayerId) -> String {
+        match self.mode {
+            GameMode::Solo => String::new(),
+            GameMode::Coop | GameMode::Versus => format!("P{} ", player + 1),
+        }
+    }
+
+    fn attract_text(&self) -> Vec<GameText> {
+        let mut texts = self.in_game_text();
+        texts.append(&mut self.start_prompt_text());
+        texts
     }
 
     fn start_game_text(&self) -> Vec<GameText> {
+        let mut texts = self.start_prompt_text();
+        texts.append(&mut self.high_score_text());
+        texts
+    }
+
+    fn start_prompt_text(&self) -> Vec<GameText> {
         let show = self.count % 20 < 15;
 
         if show {
             let (screen_x, screen_y) = self.screen_size;
 
-            vec![GameText::new(
-                String::from("Press Any Button to Start..."),
-                Game::TEXT_COLOR,
-                Game::TEXT_SIZE,
-                (
-                    screen_x / 2.0 - Game::START_GAME_TEXT_PADDING,
-                    screen_y / 2.0,
+            vec![
+                GameText::new(
+                    String::from("Press Any Button to Start..."),
+                    Game::TEXT_COLOR,
+                    Game::TEXT_SIZE,
+                    Vector::new(
+                        screen_x / 2.0 - Game::START_GAME_TEXT_PADDING,
+                        screen_y / 2.0,
+                    ),
+                ),
+                GameText::new(
+                    String::from("Press 2 for co-op or 3 for versus"),
+                    Game::TEXT_COLOR,
+                    Game::HIGH_SCORE_TEXT_SIZE,
+                    Vector::new(
+                        screen_x / 2.0 - Game::COOP_HINT_LEFT_PADDING,
+                        screen_y / 2.0 + Game::COOP_HINT_TOP_PADDING,
+                    ),
                 ),
-            )]
+            ]
         } else {
             Vec::new()
         }
     }
 
+    fn high_score_text(&self) -> Vec<GameText> {
+        let entries = self.high_scores.entries();
+        if entries.is_empty() {
+            return Vec::new();
+        }
+
+        let (screen_x, screen_y) = self.screen_size;
+        let pos_x = screen_x / 2.0 - Game::HIGH_SCORE_LEFT_PADDING;
+        let top_y = screen_y / 2.0 + Game::HIGH_SCORE_TOP_PADDING;
+
+        let header = GameText::new(
+            String::from("High Scores"),
+            Game::TEXT_COLOR,
+            Game::HIGH_SCORE_TEXT_SIZE,
+            Vector::new(pos_x, top_y),
+        );
+
+        let rows = entries.iter().enumerate().map(|(i, entry)| {
+            GameText::new(
+                format!(
+                    "{}. {}  {:>6}  L{}  {}  {}",
+                    i + 1,
+                    entry.name(),
+                    entry.score(),
+                    entry.level(),
+                    entry.mode().name(),
+                    entry.date()
+                ),
+                Game::TEXT_COLOR,
+                Game::HIGH_SCORE_TEXT_SIZE,
+                Vector::new(pos_x, top_y + (i + 1) as f64 * Game::HIGH_SCORE_LINE_HEIGHT),
+            )
+        });
+
+        std::iter::once(header).chain(rows).collect()
+    }
+
     fn inc_counter(&mut self) {
         let new_count = self.count.checked_add(1);
         match new_count {
@@ -246,3 +1105,421 @@ impl Game {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_game() -> Game {
+        Game::new(800.0, 600.0, Box::new(MemoryStorage::default())).unwrap()
+    }
+
+    #[test]
+    fn next_turn_should_start_the_attract_mode_after_inactivity() {
+        // Arrange
+        let mut game = new_game();
+
+        // Act
+        for _ in 0..GameConfig::DEFAULT.attract_delay {
+            game.next_turn();
+        }
+
+        // Assert
+        assert!(game.is_demo());
+        assert!(game.hero_position(Game::PLAYER_ONE).is_some());
+    }
+
+    #[test]
+    fn toggle_pause_should_freeze_the_game_until_resumed() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+        game.next_turn();
+        let position = game.hero_position(Game::PLAYER_ONE);
+        let stars = game.render_frame().commands()[0].clone();
+
+        // Act
+        game.toggle_pause();
+        for _ in 0..10 {
+            game.move_player(Game::PLAYER_ONE, Direction::LEFT);
+            game.next_turn();
+        }
+
+        // Assert
+        assert!(game.is_paused());
+        assert_eq!(game.hero_position(Game::PLAYER_ONE), position);
+        assert_eq!(game.render_frame().commands()[0], stars);
+        assert!(game.texts().iter().any(|text| text.content() == "Paused"));
+        game.toggle_pause();
+        assert!(!game.is_paused());
+    }
+
+    #[test]
+    fn toggle_pause_should_be_ignored_outside_of_a_game() {
+        // Arrange
+        let mut game = new_game();
+
+        // Act
+        game.toggle_pause();
+
+        // Assert
+        assert!(!game.is_paused());
+    }
+
+    #[test]
+    fn key_pressed_should_leave_the_attract_mode_and_start_a_fresh_game() {
+        // Arrange
+        let mut game = new_game();
+        for _ in 0..GameConfig::DEFAULT.attract_delay + 200 {
+            game.next_turn();
+        }
+
+        // Act
+        game.key_pressed();
+
+        // Assert
+        assert!(!game.is_demo());
+        assert!(game.is_in_game());
+        assert_eq!(game.player_stats(Game::PLAYER_ONE).unwrap().score, 0);
+        assert!(game.enemy_positions().is_empty());
+    }
+
+    #[test]
+    fn move_player_should_leave_the_attract_mode_and_start_a_fresh_game() {
+        // Arrange
+        let mut game = new_game();
+        for _ in 0..GameConfig::DEFAULT.attract_delay {
+            game.next_turn();
+        }
+
+        // Act
+        game.move_player(Game::PLAYER_ONE, Direction::LEFT);
+
+        // Assert
+        assert!(!game.is_demo());
+        assert!(game.is_in_game());
+    }
+
+    #[test]
+    fn toggle_pause_should_leave_the_attract_mode_for_the_title_screen() {
+        // Arrange
+        let mut game = new_game();
+        for _ in 0..GameConfig::DEFAULT.attract_delay {
+            game.next_turn();
+        }
+
+        // Act
+        game.toggle_pause();
+
+        // Assert
+        assert!(!game.is_demo());
+        assert!(!game.is_in_game());
+        assert!(!game.is_paused());
+    }
+
+    #[test]
+    fn attract_mode_should_fly_the_demo_hero_without_leaving_the_demo() {
+        // Arrange
+        let mut game = new_game();
+        game.seed_random(3);
+
+        // Act
+        for _ in 0..GameConfig::DEFAULT.attract_delay + 400 {
+            game.next_turn();
+        }
+
+        // Assert
+        assert!(game.is_demo());
+        assert!(!game.is_in_game());
+        assert!(game.player_stats(Game::PLAYER_ONE).unwrap().shots_fired > 0);
+    }
+
+    #[test]
+    fn fire_player_attack_should_start_a_game_from_the_title_screen() {
+        // Arrange
+        let mut game = new_game();
+
+        // Act
+        game.fire_player_attack(Game::PLAYER_ONE);
+
+        // Assert
+        assert!(game.is_in_game());
+        assert_eq!(game.mode(), GameMode::Solo);
+    }
+
+    #[test]
+    fn start_should_begin_at_the_configured_starting_level() {
+        // Arrange
+        let config = GameConfig {
+            starting_level: 4,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(config, Box::new(MemoryStorage::default())).unwrap();
+
+        // Act
+        game.start(GameMode::Solo);
+        game.next_turn();
+
+        // Assert
+        assert_eq!(game.level(), 4);
+    }
+
+    #[test]
+    fn attract_mode_should_never_end_the_game_or_record_high_scores() {
+        // Arrange
+        let mut game = new_game();
+
+        // Act & Assert
+        for _ in 0..GameConfig::DEFAULT.attract_delay + GameConfig::DEFAULT.attract_duration * 2 {
+            game.next_turn();
+            assert!(!game.is_over());
+        }
+        assert!(game.high_scores().is_empty());
+    }
+
+    #[test]
+    fn game_over_should_enter_the_initials_of_a_qualifying_score_and_record_it() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+        game.scores[Game::PLAYER_ONE].kill(100.0);
+        while !game.heroes[Game::PLAYER_ONE].is_out_of_lives() {
+            game.heroes[Game::PLAYER_ONE].lose_life();
+        }
+        game.next_turn();
+        let entering = matches!(game.scene, Scene::EnterInitials(_));
+
+        // Act
+        game.fire_player_attack(Game::PLAYER_ONE);
+        for _ in 0..Game::INITIALS_INPUT_DELAY {
+            game.next_turn();
+        }
+        game.move_player(Game::PLAYER_ONE, Direction::UP);
+        for _ in 0..InitialsEntry::LENGTH {
+            game.fire_player_attack(Game::PLAYER_ONE);
+        }
+
+        // Assert
+        assert!(entering);
+        assert!(game.is_over());
+        assert_eq!(game.high_scores().len(), 1);
+        assert_eq!(game.high_scores()[0].name(), "BAA");
+        assert_eq!(game.high_scores()[0].score(), game.total_score());
+    }
+
+    #[test]
+    fn initials_should_ignore_inputs_right_after_the_game_ends() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+        game.scores[Game::PLAYER_ONE].kill(100.0);
+        game.game_over();
+
+        // Act
+        for _ in 0..InitialsEntry::LENGTH {
+            game.move_player(Game::PLAYER_ONE, Direction::UP);
+            game.fire_player_attack(Game::PLAYER_ONE);
+        }
+
+        // Assert
+        assert!(game.high_scores().is_empty());
+        assert!(matches!(game.scene, Scene::EnterInitials(entry) if entry.name() == "AAA"));
+    }
+
+    #[test]
+    fn start_should_give_solo_heroes_a_single_life_and_coop_heroes_several() {
+        // Arrange
+        let mut solo = new_game();
+        let mut coop = new_game();
+
+        // Act
+        solo.start(GameMode::Solo);
+        coop.start(GameMode::Coop);
+
+        // Assert
+        assert_eq!(solo.lives(Game::PLAYER_ONE), GameConfig::DEFAULT.hero.lives);
+        assert_eq!(GameConfig::DEFAULT.hero.lives, 1);
+        assert_eq!(
+            coop.lives(Game::PLAYER_TWO),
+            GameConfig::DEFAULT.hero.coop_lives
+        );
+    }
+
+    #[test]
+    fn start_should_run_the_clock_of_timed_modes_only() {
+        // Arrange
+        let mut solo = new_game();
+        let mut versus = Game::new(1000.0, 600.0, Box::new(MemoryStorage::default())).unwrap();
+
+        // Act
+        solo.start(GameMode::Solo);
+        versus.start(GameMode::Versus);
+
+        // Assert
+        assert_eq!(solo.remaining_ticks, 0);
+        assert_eq!(
+            versus.remaining_ticks,
+            GameConfig::DEFAULT.versus_time_limit
+        );
+        let clock = format!(
+            "{}",
+            GameConfig::DEFAULT
+                .versus_time_limit
+                .div_ceil(Game::TICKS_PER_SECOND)
+        );
+        let timer = versus
+            .texts()
+            .into_iter()
+            .find(|text| text.content() == clock)
+            .unwrap();
+        assert_eq!(
+            timer.position(),
+            Vector::new(500.0 - Game::TIMER_TEXT_LEFT_PADDING, Game::TIMER_TEXT_TOP)
+        );
+    }
+
+    #[test]
+    fn seed_random_should_make_games_repeatable() {
+        // Arrange
+        let mut first = new_game();
+        let mut second = new_game();
+
+        // Act
+        for game in [&mut first, &mut second] {
+            game.seed_random(42);
+            game.start(GameMode::Solo);
+            for _ in 0..400 {
+                game.next_turn();
+            }
+        }
+
+        // Assert
+        assert_eq!(first.render_frame(), second.render_frame());
+    }
+
+    #[test]
+    fn new_should_reject_a_screen_too_small_to_play() {
+        // Act
+        let result = Game::new(0.5, 600.0, Box::new(MemoryStorage::default()));
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid { field: "width", .. })
+        ));
+    }
+
+    #[test]
+    fn with_config_should_reject_an_invalid_config() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.hero.lives = 0;
+
+        // Act
+        let result = Game::with_config(config, Box::new(MemoryStorage::default()));
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "hero.lives",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn with_config_should_apply_the_configured_tunables() {
+        // Arrange
+        let mut config = GameConfig::default();
+        config.hero.lives = 5;
+
+        // Act
+        let mut game = Game::with_config(config, Box::new(MemoryStorage::default())).unwrap();
+        game.start(GameMode::Solo);
+
+        // Assert
+        assert_eq!(game.lives(Game::PLAYER_ONE), 5);
+        assert_eq!(game.config().hero.lives, 5);
+    }
+
+    #[test]
+    fn next_turn_should_play_the_waves_of_the_mode_campaign() {
+        // Arrange
+        let mut game = new_game();
+        let campaign = Campaign {
+            waves: vec![
+                Wave {
+                    name: String::from("calm"),
+                    spawns: vec![],
+                    clear: Clear::AfterTicks(10),
+                },
+                Wave {
+                    name: String::from("ambush"),
+                    spawns: vec![Spawn {
+                        tick: 0,
+                        kind: EnemyKind::Fighter,
+                        entry: Entry::Top { x: Some(0.5) },
+                        formation: Formation::Row {
+                            count: 3,
+                            spacing: 50.0,
+                        },
+                    }],
+                    clear: Clear::NoEnemiesLeft,
+                },
+            ],
+            loop_from: 0,
+        };
+        game.set_campaign(GameMode::Solo, campaign).unwrap();
+        game.start(GameMode::Solo);
+
+        // Act
+        for _ in 0..10 {
+            game.next_turn();
+        }
+        let calm = game.enemy_positions().len();
+        game.next_turn();
+
+        // Assert
+        assert_eq!(calm, 0);
+        assert_eq!(game.enemy_positions().len(), 3);
+        assert_eq!(game.level(), Game::INITIAL_LEVEL + 1);
+    }
+
+    #[test]
+    fn set_campaign_should_reject_an_empty_campaign() {
+        // Arrange
+        let mut game = new_game();
+        let campaign = Campaign {
+            waves: vec![],
+            loop_from: 0,
+        };
+
+        // Act
+        let result = game.set_campaign(GameMode::Coop, campaign);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn render_frame_should_draw_the_hud_above_the_ships() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+
+        // Act
+        let frame = game.render_frame();
+
+        // Assert
+        let layers: Vec<Layer> = frame
+            .commands()
+            .iter()
+            .map(|command| command.layer)
+            .collect();
+        assert_eq!(layers.first(), Some(&Layer::Background));
+        assert!(layers.contains(&Layer::Heroes));
+        assert_eq!(layers.last(), Some(&Layer::Hud));
+        assert_eq!(frame.size(), game.screen_size());
+    }
+}
@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game_objects::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MovementTuning {
+    pub acceleration: f64,
+    pub max_speed: f64,
+    pub drag: f64,
+    pub dash_speed: f64,
+    pub dash_cooldown: u32,
+}
+
+impl MovementTuning {
+    pub const ARCADE: MovementTuning = MovementTuning {
+        acceleration: 30.0,
+        max_speed: 30.0,
+        drag: 1.0,
+        dash_speed: 120.0,
+        dash_cooldown: 40,
+    };
+
+    pub const FLOATY: MovementTuning = MovementTuning {
+        acceleration: 4.0,
+        max_speed: 14.0,
+        drag: 0.04,
+        dash_speed: 24.0,
+        dash_cooldown: 60,
+    };
+}
+
+impl Default for MovementTuning {
+    fn default() -> MovementTuning {
+        MovementTuning::ARCADE
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Inertia {
+    tuning: MovementTuning,
+    velocity: Velocity,
+    facing: Vector,
+    dash_cooldown: u32,
+}
+
+impl Inertia {
+    pub fn new(tuning: MovementTuning) -> Inertia {
+        Inertia {
+            tuning,
+            velocity: Vector::ZERO,
+            facing: Vector::ZERO,
+            dash_cooldown: 0,
+        }
+    }
+
+    pub fn thrust(&mut self, direction: Direction) {
+        let thrust = direction.vector();
+        self.facing = thrust;
+
+        let velocity = self.velocity + thrust * self.tuning.acceleration;
+        self.velocity = if velocity.length() > self.tuning.max_speed {
+            velocity.normalize() * self.tuning.max_speed
+        } else {
+            velocity
+        };
+    }
+
+    pub fn dash(&mut self) -> bool {
+        if self.dash_cooldown > 0 || self.facing == Vector::ZERO {
+            return false;
+        }
+
+        self.velocity = self.facing * self.tuning.dash_speed;
+        self.dash_cooldown = self.tuning.dash_cooldown;
+        true
+    }
+
+    pub fn step(&mut self) -> Velocity {
+        let displacement = self.velocity;
+
+        self.velocity = self.velocity * (1.0 - self.tuning.drag.clamp(0.0, 1.0));
+        self.dash_cooldown = self.dash_cooldown.saturating_sub(1);
+
+        displacement
+    }
+
+    pub fn reset(&mut self) {
+        *self = Inertia::new(self.tuning);
+    }
+
+    pub fn stop(&mut self, horizontal: bool, vertical: bool) {
+        if horizontal {
+            self.velocity.x = 0.0;
+        }
+        if vertical {
+            self.velocity.y = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_should_return_the_velocity_gained_from_thrust() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::FLOATY);
+        inertia.thrust(Direction::RIGHT);
+
+        // Act
+        let displacement = inertia.step();
+
+        // Assert
+        assert_eq!(
+            displacement,
+            Vector::new(MovementTuning::FLOATY.acceleration, 0.0)
+        );
+    }
+
+    #[test]
+    fn thrust_should_not_exceed_max_speed() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::FLOATY);
+
+        // Act
+        for _ in 0..20 {
+            inertia.thrust(Direction::UP);
+        }
+
+        // Assert
+        assert_eq!(inertia.velocity.length(), MovementTuning::FLOATY.max_speed);
+    }
+
+    #[test]
+    fn step_should_apply_drag() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::FLOATY);
+        inertia.thrust(Direction::RIGHT);
+
+        // Act
+        inertia.step();
+
+        // Assert
+        let expected = MovementTuning::FLOATY.acceleration * (1.0 - MovementTuning::FLOATY.drag);
+        assert_eq!(inertia.velocity, Vector::new(expected, 0.0));
+    }
+
+    #[test]
+    fn arcade_tuning_should_stop_after_a_single_step() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::ARCADE);
+        inertia.thrust(Direction::LEFT);
+
+        // Act
+        let first = inertia.step();
+        let second = inertia.step();
+
+        // Assert
+        assert_eq!(
+            first,
+            Vector::new(-MovementTuning::ARCADE.acceleration, 0.0)
+        );
+        assert_eq!(second, Vector::ZERO);
+    }
+
+    #[test]
+    fn dash_should_launch_in_the_facing_direction() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::FLOATY);
+        inertia.thrust(Direction::DOWN);
+
+        // Act
+        let dashed = inertia.dash();
+
+        // Assert
+        assert!(dashed);
+        assert_eq!(
+            inertia.velocity,
+            Vector::new(0.0, MovementTuning::FLOATY.dash_speed)
+        );
+    }
+
+    #[test]
+    fn dash_should_wait_for_cooldown() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::FLOATY);
+        inertia.thrust(Direction::DOWN);
+        inertia.dash();
+
+        // Act
+        let dashed = inertia.dash();
+
+        // Assert
+        assert!(!dashed);
+    }
+
+    #[test]
+    fn dash_should_not_happen_before_any_thrust() {
+        // Arrange
+        let mut inertia = Inertia::new(MovementTuning::FLOATY);
+
+        // Act
+        let dashed = inertia.dash();
+
+        // Assert
+        assert!(!dashed);
+        assert_eq!(inertia.velocity, Vector::ZERO);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::game_objects::*;
+use crate::viewport::Rect;
+
+/// Drawing order of the commands in a frame, back to front.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Layer {
+    Background,
+    Enemies,
+    Heroes,
+    Hud,
+}
+
+/// Horizontal alignment of a text relative to its position, which is on the
+/// text baseline.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Anchor {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Primitive {
+    FillPolygon {
+        points: Coord,
+        color: Color,
+    },
+    StrokePolygon {
+        points: Coord,
+        color: Color,
+        width: f64,
+    },
+    Rect {
+        rect: Rect,
+        color: Color,
+    },
+    Circle {
+        center: Position,
+        radius: f64,
+        color: Color,
+    },
+    Text {
+        content: String,
+        position: Position,
+        font_size: FontSize,
+        anchor: Anchor,
+        color: Color,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderCommand {
+    pub layer: Layer,
+    pub primitive: Primitive,
+}
+
+/// Everything to draw for one frame, in logical coordinates. Backends clear
+/// the screen with `background` and draw `commands` in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderFrame {
+    size: ScreenSize,
+    background: Color,
+    commands: Vec<RenderCommand>,
+}
+
+impl RenderFrame {
+    pub const BACKGROUND: Color = [0.0, 0.0, 0.0, 1.0];
+
+    pub fn new(size: ScreenSize) -> RenderFrame {
+        RenderFrame {
+            size,
+            background: RenderFrame::BACKGROUND,
+            commands: Vec::new(),
+        }
+    }
+
+    pub fn size(&self) -> ScreenSize {
+        self.size
+    }
+
+    pub fn background(&self) -> Color {
+        self.background
+    }
+
+    pub fn commands(&self) -> &[RenderCommand] {
+        &self.commands
+    }
+
+    /// Adds a primitive above everything already in its layer.
+    pub fn push(&mut self, layer: Layer, primitive: Primitive) {
+        let index = self
+            .commands
+            .partition_point(|command| command.layer <= layer);
+        self.commands
+            .insert(index, RenderCommand { layer, primitive });
+    }
+
+    pub fn push_object(&mut self, layer: Layer, object: &dyn SpaceObject) {
+        self.push(
+            layer,
+            Primitive::FillPolygon {
+                points: object.coord(),
+                color: object.color(),
+            },
+        );
+    }
+
+    pub fn push_text(&mut self, layer: Layer, text: &GameText) {
+        self.push(
+            layer,
+            Primitive::Text {
+                content: text.content(),
+                position: text.position(),
+                font_size: text.font_size(),
+                anchor: Anchor::Left,
+                color: text.color(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f64) -> Primitive {
+        Primitive::Rect {
+            rect: [x, 0.0, 1.0, 1.0],
+            color: [1.0, 1.0, 1.0, 1.0],
+        }
+    }
+
+    #[test]
+    fn push_should_keep_commands_ordered_by_layer() {
+        // Arrange
+        let mut frame = RenderFrame::new((800.0, 600.0));
+
+        // Act
+        frame.push(Layer::Hud, rect(1.0));
+        frame.push(Layer::Background, rect(2.0));
+        frame.push(Layer::Hud, rect(3.0));
+        frame.push(Layer::Enemies, rect(4.0));
+
+        // Assert
+        let order: Vec<Primitive> = frame
+            .commands()
+            .iter()
+            .map(|command| command.primitive.clone())
+            .collect();
+        assert_eq!(order, vec![rect(2.0), rect(4.0), rect(1.0), rect(3.0)]);
+    }
+
+    #[test]
+    fn push_text_should_anchor_texts_on_the_left() {
+        // Arrange
+        let mut frame = RenderFrame::new((800.0, 600.0));
+        let text = GameText::new(
+            String::from("Score"),
+            [1.0, 1.0, 0.0, 1.0],
+            32,
+            Vector::new(30.0, 30.0),
+        );
+
+        // Act
+        frame.push_text(Layer::Hud, &text);
+
+        // Assert
+        assert!(matches!(
+            frame.commands()[0].primitive,
+            Primitive::Text {
+                anchor: Anchor::Left,
+                font_size: 32,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ConfigError, GameConfig};
+use crate::game_objects::Direction;
+use crate::high_score::{HighScore, MemoryStorage};
+use crate::waves::Campaign;
+use crate::{Game, GameMode, PlayerId};
+
+/// A player input as the frontends send it to the game.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ReplayInput {
+    Start(GameMode),
+    KeyPressed,
+    Confirm,
+    Pause,
+    Move(PlayerId, Direction),
+    Fire(PlayerId),
+    Dash(PlayerId),
+}
+
+impl ReplayInput {
+    pub fn apply(&self, game: &mut Game) {
+        match *self {
+            ReplayInput::Start(mode) => game.start(mode),
+            ReplayInput::KeyPressed => game.key_pressed(),
+            ReplayInput::Confirm => game.confirm(),
+            ReplayInput::Pause => game.toggle_pause(),
+            ReplayInput::Move(player, direction) => game.move_player(player, direction),
+            ReplayInput::Fire(player) => game.fire_player_attack(player),
+            ReplayInput::Dash(player) => game.dash_player(player),
+        }
+    }
+}
+
+/// An input sent before the turn `tick` was played.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    pub tick: u64,
+    pub input: ReplayInput,
+}
+
+/// Everything needed to play a session again: the random seed, the game
+/// settings, the high score table and the inputs of every turn. Replays start
+/// on the title screen.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    pub ticks: u64,
+    #[serde(default)]
+    pub config: GameConfig,
+    #[serde(default)]
+    pub campaign: Option<Campaign>,
+    /// The table when the session started, which decides whether a game
+    /// over goes to the initials entry.
+    #[serde(default)]
+    pub high_scores: Vec<HighScore>,
+    #[serde(default)]
+    pub events: Vec<ReplayEvent>,
+}
+
+impl Replay {
+    pub fn new(
+        seed: u64,
+        config: GameConfig,
+        campaign: Option<Campaign>,
+        high_scores: Vec<HighScore>,
+    ) -> Replay {
+        Replay {
+            seed,
+            ticks: 0,
+            config,
+            campaign,
+            high_scores,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn from_ron(text: &str) -> Result<Replay, ConfigError> {
+        let replay: Replay =
+            ron::from_str(text).map_err(|error| ConfigError::Parse(error.to_string()))?;
+        replay.config.validate()?;
+        if let Some(campaign) = &replay.campaign {
+            campaign.validate()?;
+        }
+        // Playback reads the events in order, one tick after the other.
+        let sorted = replay
+            .events
+            .windows(2)
+            .all(|pair| pair[0].tick <= pair[1].tick);
+        if !sorted {
+            return Err(ConfigError::Invalid {
+                field: "events",
+                reason: "must be sorted by tick",
+            });
+        }
+        Ok(replay)
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("Replays are always serializable")
+    }
+
+    /// Records an input for the turn that is about to be played.
+    pub fn record(&mut self, input: ReplayInput) {
+        self.events.push(ReplayEvent {
+            tick: self.ticks,
+            input,
+        });
+    }
+
+    /// Records that a turn was played.
+    pub fn next_turn(&mut self) {
+        self.ticks += 1;
+    }
+
+    /// The inputs recorded on a tick, to play a replay one turn at a time.
+    pub fn inputs_at(&self, tick: u64) -> impl Iterator<Item = ReplayInput> + '_ {
+        let first = self.events.partition_point(|event| event.tick < tick);
+        self.events[first..]
+            .iter()
+            .take_while(move |event| event.tick == tick)
+            .map(|event| event.input)
+    }
+
+    /// Seeds the random generator and builds the game the replay starts from.
+    pub fn start(&self) -> Result<Game, ConfigError> {
+        let storage = MemoryStorage::new(&self.high_scores);
+        let mut game = Game::with_config(self.config, Box::new(storage))?;
+        game.seed_random(self.seed);
+        if let Some(campaign) = &self.campaign {
+            for mode in [GameMode::Solo, GameMode::Coop] {
+                game.set_campaign(mode, campaign.clone())?;
+            }
+        }
+        Ok(game)
+    }
+
+    /// Plays the whole replay, calling `on_turn` after every turn with the
+    /// number of turns played so far.
+    pub fn play(&self, mut on_turn: impl FnMut(u64, &Game)) -> Result<Game, ConfigError> {
+        let mut game = self.start()?;
+        let mut events = self.events.iter().peekable();
+
+        for tick in 0..self.ticks {
+            while let Some(event) = events.next_if(|event| event.tick == tick) {
+                event.input.apply(&mut game);
+            }
+            game.next_turn();
+            on_turn(tick + 1, &game);
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded_session(seed: u64, ticks: u64) -> (Replay, Game) {
+        let mut replay = Replay::new(seed, GameConfig::DEFAULT, None, Vec::new());
+        let mut game = replay.start().unwrap();
+
+        replay.record(ReplayInput::Start(GameMode::Solo));
+        game.start(GameMode::Solo);
+        for tick in 0..ticks {
+            if tick % 7 == 0 {
+                let input = ReplayInput::Move(Game::PLAYER_ONE, Direction::LEFT);
+                replay.record(input);
+                input.apply(&mut game);
+            }
+            if tick % 11 == 0 {
+                replay.record(ReplayInput::Fire(Game::PLAYER_ONE));
+                game.fire_player_attack(Game::PLAYER_ONE);
+            }
+            game.next_turn();
+            replay.next_turn();
+        }
+
+        (replay, game)
+    }
+
+    #[test]
+    fn play_should_reproduce_the_recorded_session() {
+        // Arrange
+        let (replay, recorded) = recorded_session(3, 300);
+
+        // Act
+        let replayed = replay.play(|_, _| {}).unwrap();
+
+        // Assert
+        assert_eq!(replayed.render_frame(), recorded.render_frame());
+        assert_eq!(
+            replayed.player_stats(Game::PLAYER_ONE),
+            recorded.player_stats(Game::PLAYER_ONE)
+        );
+    }
+
+    #[test]
+    fn play_should_report_every_turn() {
+        // Arrange
+        let (replay, _) = recorded_session(3, 20);
+        let mut turns = Vec::new();
+
+        // Act
+        replay.play(|tick, _| turns.push(tick)).unwrap();
+
+        // Assert
+        assert_eq!(turns, (1..=20).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn start_should_load_the_high_scores_the_session_started_with() {
+        // Arrange
+        let high_scores = vec![
+            HighScore::new(String::from("AAA"), 5000, 3, GameMode::Solo),
+            HighScore::new(String::from("BBB"), 2000, 2, GameMode::Coop),
+        ];
+        let replay = Replay::new(3, GameConfig::DEFAULT, None, high_scores.clone());
+
+        // Act
+        let game = replay.start().unwrap();
+
+        // Assert
+        assert_eq!(game.high_scores(), high_scores.as_slice());
+    }
+
+    #[test]
+    fn from_ron_should_read_the_high_scores_to_ron_writes() {
+        // Arrange
+        let high_scores = vec![HighScore::new(String::from("AAA"), 5000, 3, GameMode::Solo)];
+        let replay = Replay::new(3, GameConfig::DEFAULT, None, high_scores);
+
+        // Act
+        let parsed = Replay::from_ron(&replay.to_ron()).unwrap();
+
+        // Assert
+        assert_eq!(parsed, replay);
+    }
+
+    #[test]
+    fn from_ron_should_reject_unsorted_events() {
+        // Arrange
+        let (mut replay, _) = recorded_session(3, 30);
+        let last = replay.events.len() - 1;
+        replay.events.swap(0, last);
+
+        // Act
+        let result = Replay::from_ron(&replay.to_ron());
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ConfigError::Invalid {
+                field: "events",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn inputs_at_should_return_the_inputs_of_one_tick() {
+        // Arrange
+        let (replay, _) = recorded_session(3, 30);
+
+        // Act
+        let inputs: Vec<ReplayInput> = replay.inputs_at(14).collect();
+
+        // Assert
+        assert_eq!(
+            inputs,
+            vec![ReplayInput::Move(Game::PLAYER_ONE, Direction::LEFT)]
+        );
+        assert_eq!(replay.inputs_at(15).count(), 0);
+    }
+
+    #[test]
+    fn from_ron_should_read_what_to_ron_writes() {
+        // Arrange
+        let (replay, _) = recorded_session(5, 30);
+
+        // Act
+        let parsed = Replay::from_ron(&replay.to_ron()).unwrap();
+
+        // Assert
+        assert_eq!(parsed, replay);
+    }
+
+    #[test]
+    fn from_ron_should_reject_invalid_configs() {
+        // Arrange
+        let text = "(seed: 1, ticks: 10, config: (width: -1.0))";
+
+        // Act
+        let result = Replay::from_ron(text);
+
+        // Assert
+        assert!(matches!(result, Err(ConfigError::Invalid { .. })));
+    }
+}
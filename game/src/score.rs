@@ -0,0 +1,277 @@
+use crate::config::ScoreConfig;
+use crate::GameScore;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlayerStats {
+    pub score: GameScore,
+    pub kills: u32,
+    pub shots_fired: u32,
+    pub accuracy: u32,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ScoreBoard {
+    config: ScoreConfig,
+    kill_points: GameScore,
+    combo_bonus: GameScore,
+    close_kill_bonus: GameScore,
+    accuracy_bonus: GameScore,
+    multiplier: u32,
+    chain_ticks: u32,
+    kills: u32,
+    shots_fired: u32,
+}
+
+impl ScoreBoard {
+    pub fn new(config: ScoreConfig) -> ScoreBoard {
+        ScoreBoard {
+            config,
+            kill_points: 0,
+            combo_bonus: 0,
+            close_kill_bonus: 0,
+            accuracy_bonus: 0,
+            multiplier: 1,
+            chain_ticks: 0,
+            kills: 0,
+            shots_fired: 0,
+        }
+    }
+
+    pub fn combo_window(&self) -> u32 {
+        self.config.combo_window
+    }
+
+    pub fn tick(&mut self) {
+        if self.chain_ticks > 0 {
+            self.chain_ticks -= 1;
+            if self.chain_ticks == 0 {
+                self.break_chain();
+            }
+        }
+    }
+
+    pub fn shot_fired(&mut self) {
+        self.shots_fired += 1;
+    }
+
+    pub fn kill(&mut self, distance_to_hero: f64) {
+        if self.chain_ticks > 0 {
+            self.multiplier = (self.multiplier + 1).min(self.config.max_multiplier);
+        }
+        self.chain_ticks = self.config.combo_window;
+        self.kills += 1;
+
+        self.kill_points += self.config.points;
+        self.combo_bonus += self.config.points * (self.multiplier - 1);
+
+        if distance_to_hero < self.config.close_kill_distance {
+            self.close_kill_bonus += self.config.close_kill_bonus;
+        }
+    }
+
+    pub fn break_chain(&mut self) {
+        self.multiplier = 1;
+        self.chain_ticks = 0;
+    }
+
+    pub fn finish(&mut self) {
+        self.break_chain();
+        if self.shots_fired >= self.config.accuracy_min_shots {
+            self.accuracy_bonus = self.accuracy() * self.config.accuracy_bonus_per_percent;
+        }
+    }
+
+    pub fn total(&self) -> GameScore {
+        self.kill_points + self.combo_bonus + self.close_kill_bonus + self.accuracy_bonus
+    }
+
+    pub fn accuracy(&self) -> u32 {
+        if self.shots_fired == 0 {
+            return 0;
+        }
+
+        (self.kills.min(self.shots_fired) * 100) / self.shots_fired
+    }
+
+    pub fn stats(&self) -> PlayerStats {
+        PlayerStats {
+            score: self.total(),
+            kills: self.kills,
+            shots_fired: self.shots_fired,
+            accuracy: self.accuracy(),
+        }
+    }
+
+    pub fn multiplier(&self) -> u32 {
+        self.multiplier
+    }
+
+    pub fn chain_ticks(&self) -> u32 {
+        self.chain_ticks
+    }
+
+    pub fn kills(&self) -> u32 {
+        self.kills
+    }
+
+    pub fn kill_points(&self) -> GameScore {
+        self.kill_points
+    }
+
+    pub fn combo_bonus(&self) -> GameScore {
+        self.combo_bonus
+    }
+
+    pub fn close_kill_bonus(&self) -> GameScore {
+        self.close_kill_bonus
+    }
+
+    pub fn accuracy_bonus(&self) -> GameScore {
+        self.accuracy_bonus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: ScoreConfig = ScoreConfig::DEFAULT;
+
+    const FAR_AWAY: f64 = CONFIG.close_kill_distance + 1.0;
+
+    #[test]
+    fn kill_should_add_flat_points_on_the_first_kill() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+
+        // Act
+        score.kill(FAR_AWAY);
+
+        // Assert
+        assert_eq!(score.total(), CONFIG.points);
+        assert_eq!(score.multiplier(), 1);
+    }
+
+    #[test]
+    fn kill_should_raise_multiplier_when_chained_inside_the_window() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+        score.kill(FAR_AWAY);
+
+        // Act
+        score.tick();
+        score.kill(FAR_AWAY);
+
+        // Assert
+        assert_eq!(score.multiplier(), 2);
+        assert_eq!(score.total(), CONFIG.points * 3);
+    }
+
+    #[test]
+    fn kill_should_not_raise_multiplier_above_max() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+
+        // Act
+        for _ in 0..CONFIG.max_multiplier + 3 {
+            score.kill(FAR_AWAY);
+        }
+
+        // Assert
+        assert_eq!(score.multiplier(), CONFIG.max_multiplier);
+    }
+
+    #[test]
+    fn tick_should_break_chain_when_window_expires() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+        score.kill(FAR_AWAY);
+        score.kill(FAR_AWAY);
+
+        // Act
+        for _ in 0..CONFIG.combo_window {
+            score.tick();
+        }
+
+        // Assert
+        assert_eq!(score.multiplier(), 1);
+        assert_eq!(score.chain_ticks(), 0);
+    }
+
+    #[test]
+    fn break_chain_should_reset_multiplier_and_keep_points() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+        score.kill(FAR_AWAY);
+        score.kill(FAR_AWAY);
+        let total = score.total();
+
+        // Act
+        score.break_chain();
+
+        // Assert
+        assert_eq!(score.multiplier(), 1);
+        assert_eq!(score.total(), total);
+    }
+
+    #[test]
+    fn kill_should_add_bonus_when_enemy_is_close_to_hero() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+
+        // Act
+        score.kill(CONFIG.close_kill_distance - 1.0);
+
+        // Assert
+        assert_eq!(score.close_kill_bonus(), CONFIG.close_kill_bonus);
+    }
+
+    #[test]
+    fn finish_should_add_accuracy_bonus() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+        for _ in 0..CONFIG.accuracy_min_shots {
+            score.shot_fired();
+        }
+        for _ in 0..CONFIG.accuracy_min_shots / 4 {
+            score.kill(FAR_AWAY);
+        }
+
+        // Act
+        score.finish();
+
+        // Assert
+        assert_eq!(score.accuracy(), 25);
+        assert_eq!(
+            score.accuracy_bonus(),
+            25 * CONFIG.accuracy_bonus_per_percent
+        );
+    }
+
+    #[test]
+    fn finish_should_not_add_accuracy_bonus_below_the_minimum_shots() {
+        // Arrange
+        let mut score = ScoreBoard::new(CONFIG);
+        score.shot_fired();
+        score.kill(FAR_AWAY);
+
+        // Act
+        score.finish();
+
+        // Assert
+        assert_eq!(score.accuracy(), 100);
+        assert_eq!(score.accuracy_bonus(), 0);
+    }
+
+    #[test]
+    fn accuracy_should_be_zero_when_no_shots_were_fired() {
+        // Arrange
+        let score = ScoreBoard::new(CONFIG);
+
+        // Act
+        let accuracy = score.accuracy();
+
+        // Assert
+        assert_eq!(accuracy, 0);
+    }
+}
@@ -3,29 +3,23 @@ use crate::game_objects::*;
 #[derive(Copy, Clone, Debug)]
 pub struct Shot {
     position: Position,
-    direction: Direction,
+    velocity: Velocity,
+    size: f64,
 }
 
 impl Shot {
     pub const COLOR: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
-    pub const SPEED: f64 = 10.0;
-    pub const SIZE: f64 = 5.0;
 
-    pub fn new(position: Position, direction: Direction) -> Shot {
+    pub fn new(position: Position, velocity: Velocity, size: f64) -> Shot {
         Shot {
             position,
-            direction,
+            velocity,
+            size,
         }
     }
 
     pub fn action(&mut self) {
-        let (cur_x, cur_y) = self.position;
-        let new_y = match self.direction() {
-            Direction::UP => cur_y - Shot::SPEED,
-            Direction::DOWN => cur_y + Shot::SPEED,
-            direction => panic!("Unexpected Durection {:?}", direction),
-        };
-        self.position = (cur_x, new_y);
+        self.position += self.velocity;
     }
 }
 
@@ -35,11 +29,22 @@ impl SpaceObject for Shot {
     }
 
     fn direction(&self) -> Direction {
-        self.direction
+        let Vector { x, y } = self.velocity;
+        if x.abs() > y.abs() {
+            if x < 0.0 {
+                Direction::LEFT
+            } else {
+                Direction::RIGHT
+            }
+        } else if y < 0.0 {
+            Direction::UP
+        } else {
+            Direction::DOWN
+        }
     }
 
     fn size(&self) -> f64 {
-        Shot::SIZE
+        self.size
     }
 
     fn position(&self) -> Position {
@@ -50,64 +55,108 @@ impl SpaceObject for Shot {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ShotConfig;
+
+    const SPEED: f64 = ShotConfig::DEFAULT.speed;
+    const SIZE: f64 = ShotConfig::DEFAULT.size;
 
     #[test]
-    fn update_should_not_change_x() {
+    fn update_should_not_change_x_when_moving_vertically() {
         // Arrange
         let x = 10.0;
-        let position = (x, 50.0);
-        let direction = Direction::DOWN;
-        let mut shot = Shot::new(position, direction);
+        let position = Vector::new(x, 50.0);
+        let velocity = Direction::DOWN.vector() * SPEED;
+        let mut shot = Shot::new(position, velocity, SIZE);
 
         // Act
         shot.action();
 
         // Assert
-        let (new_x, _) = shot.position;
-        assert_eq!(new_x, x);
+        assert_eq!(shot.position.x, x);
     }
 
     #[test]
     fn update_should_increase_y_by_speed_if_direction_is_down() {
         // Arrange
         let y = 140.0;
-        let position = (50.0, y);
-        let direction = Direction::DOWN;
-        let mut shot = Shot::new(position, direction);
+        let position = Vector::new(50.0, y);
+        let velocity = Direction::DOWN.vector() * SPEED;
+        let mut shot = Shot::new(position, velocity, SIZE);
 
         // Act
         shot.action();
 
         // Assert
-        let (_, new_y) = shot.position;
-        assert_eq!(new_y, y + Shot::SPEED);
+        assert_eq!(shot.position.y, y + SPEED);
     }
 
     #[test]
     fn update_should_decrease_y_by_speed_if_direction_is_up() {
         // Arrange
         let y = 140.0;
-        let position = (50.0, y);
-        let direction = Direction::UP;
-        let mut shot = Shot::new(position, direction);
+        let position = Vector::new(50.0, y);
+        let velocity = Direction::UP.vector() * SPEED;
+        let mut shot = Shot::new(position, velocity, SIZE);
 
         // Act
         shot.action();
 
         // Assert
-        let (_, new_y) = shot.position;
-        assert_eq!(new_y, y - Shot::SPEED);
+        assert_eq!(shot.position.y, y - SPEED);
     }
 
     #[test]
-    #[should_panic]
-    fn update_should_panic_if_direction_is_not_up_and_down() {
+    fn update_should_move_along_a_diagonal_velocity() {
         // Arrange
-        let position = (50.0, 100.0);
-        let direction = Direction::LEFT;
-        let mut shot = Shot::new(position, direction);
+        let position = Vector::new(50.0, 100.0);
+        let velocity = Vector::new(3.0, -4.0);
+        let mut shot = Shot::new(position, velocity, SIZE);
 
         // Act
         shot.action();
+
+        // Assert
+        assert_eq!(shot.position, Vector::new(53.0, 96.0));
+    }
+
+    #[test]
+    fn update_should_move_sideways_without_panicking() {
+        // Arrange
+        let position = Vector::new(50.0, 100.0);
+        let velocity = Direction::LEFT.vector() * SPEED;
+        let mut shot = Shot::new(position, velocity, SIZE);
+
+        // Act
+        shot.action();
+
+        // Assert
+        assert_eq!(shot.position, Vector::new(50.0 - SPEED, 100.0));
+    }
+
+    #[test]
+    fn direction_should_follow_the_main_axis_of_the_velocity() {
+        // Arrange
+        let position = Vector::new(50.0, 100.0);
+        let sideways = Shot::new(position, Vector::new(-4.0, 3.0), SIZE);
+        let upwards = Shot::new(position, Vector::new(3.0, -4.0), SIZE);
+
+        // Act
+        let directions = (sideways.direction(), upwards.direction());
+
+        // Assert
+        assert_eq!(directions, (Direction::LEFT, Direction::UP));
+    }
+
+    #[test]
+    fn coord_should_point_along_a_sideways_velocity() {
+        // Arrange
+        let position = Vector::new(50.0, 100.0);
+        let shot = Shot::new(position, Direction::RIGHT.vector() * SPEED, SIZE);
+
+        // Act
+        let coord = shot.coord();
+
+        // Assert
+        assert_eq!(coord[1], [50.0 + SIZE, 100.0]);
     }
 }
@@ -1,3 +1,4 @@
+use crate::config::ShotConfig;
 use crate::game_objects::*;
 use crate::shot::*;
 
@@ -8,6 +9,7 @@ pub struct Spaceship {
     screen_size: ScreenSize,
     color: Color,
     size: f64,
+    shot: ShotConfig,
     shots: Vec<Shot>,
 }
 
@@ -26,40 +28,77 @@ impl Spaceship {
             screen_size,
             color,
             size,
+            shot: ShotConfig::DEFAULT,
             shots: Vec::new(),
         }
     }
 
-    pub fn screen_size(&self) -> Position {
+    pub fn screen_size(&self) -> ScreenSize {
         self.screen_size
     }
 
+    pub fn set_shot_config(&mut self, shot: ShotConfig) {
+        self.shot = shot;
+    }
+
     pub fn move_to(&mut self, position: Position) {
         self.position = position;
     }
 
-    pub fn update_shot_position(&mut self) {
+    pub fn update_shot_position(&mut self) -> usize {
         let screen_size = self.screen_size();
+        let shot_count = self.shots.len();
 
         self.shots.iter_mut().for_each(|shot| shot.action());
         self.shots.retain(|shot| shot.is_visible(screen_size));
+
+        shot_count - self.shots.len()
     }
 
     pub fn fire(&mut self) {
+        let velocity = self.direction().vector() * self.shot.speed;
+        self.fire_with_velocity(velocity);
+    }
+
+    pub fn fire_with_velocity(&mut self, velocity: Velocity) {
         let position = self.position();
-        let direction = self.direction();
+        self.shots
+            .push(Shot::new(position, velocity, self.shot.size));
+    }
 
-        self.shots.push(Shot::new(position, direction));
+    pub fn fire_at(&mut self, target: Position) {
+        let aim = (target - self.position()).normalize();
+        if aim == Vector::ZERO {
+            self.fire();
+        } else {
+            self.fire_with_velocity(aim * self.shot.speed);
+        }
     }
 
-    pub fn as_game_objects(&self) -> Vec<Box<&dyn SpaceObject>> {
-        let mut objects: Vec<Box<&dyn SpaceObject>> = vec![];
+    pub fn fire_spread(&mut self, count: u32, spread: f64) {
+        if count <= 1 {
+            self.fire();
+            return;
+        }
 
-        self.shots
-            .iter()
-            .for_each(|shot| objects.push(Box::new(shot)));
+        let center = self.direction().vector().angle();
+        let step = spread / f64::from(count - 1);
+        for i in 0..count {
+            let angle = center - spread / 2.0 + step * f64::from(i);
+            self.fire_with_velocity(Vector::from_angle(angle) * self.shot.speed);
+        }
+    }
 
-        objects.push(Box::new(self));
+    pub fn shot_positions(&self) -> Vec<Position> {
+        self.shots.iter().map(|shot| shot.position()).collect()
+    }
+
+    pub fn as_game_objects(&self) -> Vec<&dyn SpaceObject> {
+        let mut objects: Vec<&dyn SpaceObject> = vec![];
+
+        self.shots.iter().for_each(|shot| objects.push(shot));
+
+        objects.push(self);
 
         objects
     }
@@ -68,10 +107,21 @@ impl Spaceship {
         self.destroyed = true;
     }
 
+    pub fn restore(&mut self) {
+        self.destroyed = false;
+        self.shots.clear();
+    }
+
     pub fn hits(&self, other: &Spaceship) -> bool {
         !other.is_destroyed() && self.shots.iter().any(|shot| other.check_collision(shot))
     }
 
+    /// Removes the shots that hit the other spaceship, so that they neither
+    /// hit again nor count as missed.
+    pub fn remove_shots_hitting(&mut self, other: &Spaceship) {
+        self.shots.retain(|shot| !other.check_collision(shot));
+    }
+
     pub fn is_destroyed(&self) -> bool {
         self.destroyed
     }
@@ -96,6 +146,7 @@ impl SpaceObject for Spaceship {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 pub mod tests {
     use super::*;
 
@@ -103,6 +154,12 @@ pub mod tests {
         spaceship.shots
     }
 
+    pub fn shot_velocity(mut shot: Shot) -> Velocity {
+        let start = shot.position();
+        shot.action();
+        shot.position() - start
+    }
+
     #[test]
     fn hits_should_return_false_whe_the_other_spaceship_is_destroyed() {
         // Arrange
@@ -110,7 +167,7 @@ pub mod tests {
         let (x, y) = (50.0, 50.0);
 
         let spaceship = Spaceship::new(
-            (x, y),
+            Vector::new(x, y),
             Direction::UP,
             (width, height),
             [1.0, 1.0, 1.0, 1.0],
@@ -118,7 +175,7 @@ pub mod tests {
         );
 
         let mut other = Spaceship::new(
-            (x + 100.0, y + 100.0),
+            Vector::new(x + 100.0, y + 100.0),
             Direction::UP,
             (width, height),
             [1.0, 1.0, 1.0, 1.0],
@@ -142,7 +199,7 @@ pub mod tests {
         let (other_x, other_y) = (50.0, 50.0);
 
         let mut spaceship = Spaceship::new(
-            (x, y),
+            Vector::new(x, y),
             Direction::UP,
             (width, height),
             [1.0, 1.0, 1.0, 1.0],
@@ -150,16 +207,18 @@ pub mod tests {
         );
 
         let other = Spaceship::new(
-            (other_x, other_y),
+            Vector::new(other_x, other_y),
             Direction::UP,
             (width, height),
             [1.0, 1.0, 1.0, 1.0],
             20.0,
         );
 
-        spaceship
-            .shots
-            .push(Shot::new((other_x, other_y), Direction::DOWN));
+        spaceship.shots.push(Shot::new(
+            Vector::new(other_x, other_y),
+            Direction::DOWN.vector() * ShotConfig::DEFAULT.speed,
+            ShotConfig::DEFAULT.size,
+        ));
 
         // Act
         let hits = spaceship.hits(&other);
@@ -167,6 +226,123 @@ pub mod tests {
         // Assert
         assert_eq!(hits, true);
     }
+
+    #[test]
+    fn remove_shots_hitting_should_remove_only_the_shots_on_the_other_spaceship() {
+        // Arrange
+        let (width, height) = (100.0, 100.0);
+        let (other_x, other_y) = (50.0, 50.0);
+
+        let mut spaceship = Spaceship::new(
+            Vector::new(10.0, 90.0),
+            Direction::UP,
+            (width, height),
+            [1.0, 1.0, 1.0, 1.0],
+            20.0,
+        );
+
+        let other = Spaceship::new(
+            Vector::new(other_x, other_y),
+            Direction::UP,
+            (width, height),
+            [1.0, 1.0, 1.0, 1.0],
+            20.0,
+        );
+
+        spaceship.shots.push(Shot::new(
+            Vector::new(other_x, other_y),
+            Direction::DOWN.vector() * ShotConfig::DEFAULT.speed,
+            ShotConfig::DEFAULT.size,
+        ));
+
+        spaceship.shots.push(Shot::new(
+            Vector::new(other_x, other_y + 40.0),
+            Direction::UP.vector() * ShotConfig::DEFAULT.speed,
+            ShotConfig::DEFAULT.size,
+        ));
+
+        // Act
+        let hits = spaceship.hits(&other);
+        spaceship.remove_shots_hitting(&other);
+
+        // Assert
+        assert!(hits);
+        assert_eq!(spaceship.shots.len(), 1);
+    }
+
+    #[test]
+    fn fire_at_should_aim_the_shot_at_the_target() {
+        // Arrange
+        let mut spaceship = Spaceship::new(
+            Vector::new(0.0, 0.0),
+            Direction::UP,
+            (100.0, 100.0),
+            [1.0, 1.0, 1.0, 1.0],
+            20.0,
+        );
+
+        // Act
+        spaceship.fire_at(Vector::new(30.0, 40.0));
+
+        // Assert
+        let velocity = shot_velocity(spaceship.shots[0]);
+        assert!((velocity.x - 0.6 * ShotConfig::DEFAULT.speed).abs() < 1e-9);
+        assert!((velocity.y - 0.8 * ShotConfig::DEFAULT.speed).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fire_spread_should_fire_count_shots_centered_on_direction() {
+        // Arrange
+        let mut spaceship = Spaceship::new(
+            Vector::new(50.0, 50.0),
+            Direction::UP,
+            (100.0, 100.0),
+            [1.0, 1.0, 1.0, 1.0],
+            20.0,
+        );
+
+        // Act
+        spaceship.fire_spread(3, std::f64::consts::FRAC_PI_2);
+
+        // Assert
+        let velocities: Vec<Velocity> = spaceship.shots.iter().map(|s| shot_velocity(*s)).collect();
+        assert_eq!(velocities.len(), 3);
+        assert!(velocities[0].x < 0.0 && velocities[0].y < 0.0);
+        assert!(velocities[1].x.abs() < 1e-9 && velocities[1].y < 0.0);
+        assert!(velocities[2].x > 0.0 && velocities[2].y < 0.0);
+    }
+
+    #[test]
+    fn update_shot_position_should_return_the_number_of_shots_that_left_the_screen() {
+        // Arrange
+        let (width, height) = (100.0, 100.0);
+
+        let mut spaceship = Spaceship::new(
+            Vector::new(50.0, 50.0),
+            Direction::UP,
+            (width, height),
+            [1.0, 1.0, 1.0, 1.0],
+            20.0,
+        );
+
+        spaceship.shots.push(Shot::new(
+            Vector::new(50.0, -15.0),
+            Direction::UP.vector() * ShotConfig::DEFAULT.speed,
+            ShotConfig::DEFAULT.size,
+        ));
+        spaceship.shots.push(Shot::new(
+            Vector::new(50.0, 50.0),
+            Direction::UP.vector() * ShotConfig::DEFAULT.speed,
+            ShotConfig::DEFAULT.size,
+        ));
+
+        // Act
+        let missed = spaceship.update_shot_position();
+
+        // Assert
+        assert_eq!(missed, 1);
+        assert_eq!(spaceship.shots.len(), 1);
+    }
     #[test]
     fn hits_should_return_false_whe_the_other_destroyed_spaceship_is_at_the_same_position_as_a_shot(
     ) {
@@ -176,18 +352,20 @@ pub mod tests {
         let (other_x, other_y) = (50.0, 50.0);
 
         let mut spaceship = Spaceship::new(
-            (x, y),
+            Vector::new(x, y),
             Direction::UP,
             (width, height),
             [1.0, 1.0, 1.0, 1.0],
             20.0,
         );
-        spaceship
-            .shots
-            .push(Shot::new((other_x, other_y), Direction::DOWN));
+        spaceship.shots.push(Shot::new(
+            Vector::new(other_x, other_y),
+            Direction::DOWN.vector() * ShotConfig::DEFAULT.speed,
+            ShotConfig::DEFAULT.size,
+        ));
 
         let mut other = Spaceship::new(
-            (other_x, other_y),
+            Vector::new(other_x, other_y),
             Direction::UP,
             (width, height),
             [1.0, 1.0, 1.0, 1.0],
@@ -1,5 +1,5 @@
 use crate::game_objects::*;
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
 #[derive(Copy, Clone)]
 pub struct Star {
@@ -13,9 +13,7 @@ impl Star {
     pub const SPEED: f64 = 5.0;
     pub const DIRECTION: Direction = Direction::DOWN;
 
-    pub fn new(screen_size: ScreenSize) -> Star {
-        let mut rng = thread_rng();
-
+    pub fn new(screen_size: ScreenSize, rng: &mut impl Rng) -> Star {
         let (width, height) = screen_size;
         let gen_x = rng.gen_range(0..(width as i32));
         let gen_y = rng.gen_range(0..(height as i32));
@@ -23,21 +21,21 @@ impl Star {
 
         Star {
             size: f64::from(size),
-            position: (f64::from(gen_x), f64::from(gen_y)),
+            position: Vector::new(f64::from(gen_x), f64::from(gen_y)),
             screen_size,
         }
     }
 
     pub fn fall(&mut self) {
         let (_, height) = self.screen_size;
-        let (cur_x, cur_y) = self.position();
+        let Vector { x: cur_x, y: cur_y } = self.position();
         let new_y = if cur_y + Star::SPEED < height {
             cur_y + Star::SPEED
         } else {
             0.0
         };
 
-        self.position = (cur_x, new_y);
+        self.position = Vector::new(cur_x, new_y);
     }
 }
 
@@ -59,7 +57,7 @@ impl SpaceObject for Star {
     }
 
     fn coord(&self) -> Coord {
-        let (x, y) = self.position();
+        let Vector { x, y } = self.position();
         let size = self.size();
         vec![[x, y], [x + size, y], [x + size, y + size], [x, y + size]]
     }
@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::game_objects::Color;
+use crate::render::{Anchor, Primitive, RenderFrame};
+
+/// Font family of the texts, matching the font bundled with the frontends.
+const FONT_FAMILY: &str = "'Nova Square', NovaSquare, sans-serif";
+/// Embedded in every document, so that screenshots look the same on machines
+/// without the font installed.
+const FONT_DATA: &[u8] = include_bytes!("../../assets/NovaSquare-Regular.ttf");
+
+/// Serializes a frame to a standalone SVG document in logical coordinates.
+/// Text sizes are in points, as in the desktop frontend.
+pub fn frame_to_svg(frame: &RenderFrame) -> String {
+    let (width, height) = frame.size();
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+        width, height, width, height
+    );
+    let _ = writeln!(
+        svg,
+        "<defs><style>@font-face{{font-family:'Nova Square';src:url(data:font/ttf;base64,{}) format('truetype');}}</style></defs>",
+        STANDARD.encode(FONT_DATA)
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="100%" height="100%" {}/>"#,
+        fill(frame.background())
+    );
+    for command in frame.commands() {
+        write_primitive(&mut svg, &command.primitive);
+    }
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+fn write_primitive(svg: &mut String, primitive: &Primitive) {
+    let _ = match primitive {
+        Primitive::FillPolygon { points, color } => writeln!(
+            svg,
+            r#"<polygon points="{}" {}/>"#,
+            polygon_points(points),
+            fill(*color)
+        ),
+        Primitive::StrokePolygon {
+            points,
+            color,
+            width,
+        } => writeln!(
+            svg,
+            r#"<polygon points="{}" fill="none" stroke="{}" stroke-opacity="{}" stroke-width="{}"/>"#,
+            polygon_points(points),
+            rgb(*color),
+            color[3],
+            width
+        ),
+        Primitive::Rect { rect, color } => {
+            let [x, y, width, height] = *rect;
+            writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" {}/>"#,
+                x,
+                y,
+                width,
+                height,
+                fill(*color)
+            )
+        }
+        Primitive::Circle {
+            center,
+            radius,
+            color,
+        } => writeln!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{}" {}/>"#,
+            center.x,
+            center.y,
+            radius,
+            fill(*color)
+        ),
+        Primitive::Text {
+            content,
+            position,
+            font_size,
+            anchor,
+            color,
+        } => writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-family="{}" font-size="{}pt" text-anchor="{}" {}>{}</text>"#,
+            position.x,
+            position.y,
+            FONT_FAMILY,
+            font_size,
+            text_anchor(*anchor),
+            fill(*color),
+            escape(content)
+        ),
+    };
+}
+
+fn polygon_points(points: &[[f64; 2]]) -> String {
+    points
+        .iter()
+        .map(|[x, y]| format!("{},{}", x, y))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn rgb(color: Color) -> String {
+    let [red, green, blue, _] = color.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round());
+    format!("rgb({},{},{})", red, green, blue)
+}
+
+fn fill(color: Color) -> String {
+    format!(r#"fill="{}" fill-opacity="{}""#, rgb(color), color[3])
+}
+
+fn text_anchor(anchor: Anchor) -> &'static str {
+    match anchor {
+        Anchor::Left => "start",
+        Anchor::Center => "middle",
+        Anchor::Right => "end",
+    }
+}
+
+fn escape(content: &str) -> String {
+    content
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_objects::Vector;
+    use crate::render::Layer;
+
+    #[test]
+    fn frame_to_svg_should_write_polygons_with_their_colors() {
+        // Arrange
+        let mut frame = RenderFrame::new((800.0, 600.0));
+        frame.push(
+            Layer::Heroes,
+            Primitive::FillPolygon {
+                points: vec![[10.0, 10.0], [20.5, 10.0], [15.0, 20.0]],
+                color: [1.0, 0.0, 0.0, 1.0],
+            },
+        );
+
+        // Act
+        let svg = frame_to_svg(&frame);
+
+        // Assert
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r#"viewBox="0 0 800 600""#));
+        assert!(svg.contains(r#"<polygon points="10,10 20.5,10 15,20" fill="rgb(255,0,0)""#));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn frame_to_svg_should_escape_and_anchor_texts() {
+        // Arrange
+        let mut frame = RenderFrame::new((800.0, 600.0));
+        frame.push(
+            Layer::Hud,
+            Primitive::Text {
+                content: String::from("<A&B>"),
+                position: Vector::new(400.0, 300.0),
+                font_size: 32,
+                anchor: Anchor::Center,
+                color: [1.0, 1.0, 0.0, 1.0],
+            },
+        );
+
+        // Act
+        let svg = frame_to_svg(&frame);
+
+        // Assert
+        assert!(svg.contains(r#"font-size="32pt" text-anchor="middle""#));
+        assert!(svg.contains(">&lt;A&amp;B&gt;</text>"));
+    }
+
+    #[test]
+    fn frame_to_svg_should_write_rects_circles_and_outlines() {
+        // Arrange
+        let mut frame = RenderFrame::new((800.0, 600.0));
+        frame.push(
+            Layer::Background,
+            Primitive::Rect {
+                rect: [0.0, 0.0, 10.0, 20.0],
+                color: [0.0, 0.0, 0.0, 1.0],
+            },
+        );
+        frame.push(
+            Layer::Hud,
+            Primitive::Circle {
+                center: Vector::new(50.0, 60.0),
+                radius: 5.0,
+                color: [1.0, 1.0, 1.0, 0.5],
+            },
+        );
+        frame.push(
+            Layer::Hud,
+            Primitive::StrokePolygon {
+                points: vec![[1.0, 1.0], [2.0, 2.0]],
+                color: [1.0, 0.0, 0.0, 1.0],
+                width: 3.0,
+            },
+        );
+
+        // Act
+        let svg = frame_to_svg(&frame);
+
+        // Assert
+        assert!(svg.contains(r#"<rect x="0" y="0" width="10" height="20" fill="rgb(0,0,0)""#));
+        assert!(svg.contains(
+            r#"<circle cx="50" cy="60" r="5" fill="rgb(255,255,255)" fill-opacity="0.5""#
+        ));
+        assert!(svg
+            .contains(r#"fill="none" stroke="rgb(255,0,0)" stroke-opacity="1" stroke-width="3""#));
+    }
+
+    #[test]
+    fn frame_to_svg_should_embed_the_game_font() {
+        // Arrange
+        let frame = RenderFrame::new((800.0, 600.0));
+
+        // Act
+        let svg = frame_to_svg(&frame);
+
+        // Assert
+        assert!(svg.contains("@font-face{font-family:'Nova Square'"));
+        assert!(svg.contains(&format!(
+            "data:font/ttf;base64,{}",
+            STANDARD.encode(FONT_DATA)
+        )));
+    }
+}
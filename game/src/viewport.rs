@@ -0,0 +1,203 @@
+use crate::game_objects::*;
+use crate::render::{Layer, Primitive, RenderFrame};
+
+pub type Rect = [f64; 4];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Viewport {
+    logical_size: ScreenSize,
+    physical_size: ScreenSize,
+    scale: f64,
+    offset: Vector,
+}
+
+impl Viewport {
+    pub const BAR_COLOR: Color = [0.0, 0.0, 0.0, 1.0];
+
+    pub fn fit(logical_size: ScreenSize, physical_size: ScreenSize) -> Viewport {
+        let (logical_width, logical_height) = logical_size;
+        let (physical_width, physical_height) = physical_size;
+
+        let scale = min(
+            physical_width / logical_width,
+            physical_height / logical_height,
+        );
+        let offset = Vector::new(
+            (physical_width - logical_width * scale) / 2.0,
+            (physical_height - logical_height * scale) / 2.0,
+        );
+
+        Viewport {
+            logical_size,
+            physical_size,
+            scale,
+            offset,
+        }
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn offset(&self) -> Vector {
+        self.offset
+    }
+
+    pub fn physical_size(&self) -> ScreenSize {
+        self.physical_size
+    }
+
+    pub fn to_physical(&self, logical: Position) -> Position {
+        logical * self.scale + self.offset
+    }
+
+    pub fn to_logical(&self, physical: Position) -> Option<Position> {
+        let (logical_width, logical_height) = self.logical_size;
+        let logical = (physical - self.offset) * (1.0 / self.scale);
+
+        let inside = logical.x >= 0.0
+            && logical.x <= logical_width
+            && logical.y >= 0.0
+            && logical.y <= logical_height;
+
+        if inside {
+            Some(logical)
+        } else {
+            None
+        }
+    }
+
+    pub fn bars(&self) -> Vec<Rect> {
+        let (physical_width, physical_height) = self.physical_size;
+        let Vector { x, y } = self.offset;
+
+        if x > 0.0 {
+            vec![
+                [0.0, 0.0, x, physical_height],
+                [physical_width - x, 0.0, x, physical_height],
+            ]
+        } else if y > 0.0 {
+            vec![
+                [0.0, 0.0, physical_width, y],
+                [0.0, physical_height - y, physical_width, y],
+            ]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// The bars in physical coordinates, to draw over the game frame
+    /// without the viewport transform.
+    pub fn bar_frame(&self) -> RenderFrame {
+        let mut frame = RenderFrame::new(self.physical_size);
+        for rect in self.bars() {
+            frame.push(
+                Layer::Hud,
+                Primitive::Rect {
+                    rect,
+                    color: Viewport::BAR_COLOR,
+                },
+            );
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_should_pillarbox_a_wider_window() {
+        // Arrange
+        let logical = (800.0, 600.0);
+
+        // Act
+        let viewport = Viewport::fit(logical, (1600.0, 900.0));
+
+        // Assert
+        assert_eq!(viewport.scale(), 1.5);
+        assert_eq!(viewport.offset(), Vector::new(200.0, 0.0));
+        assert_eq!(viewport.bars().len(), 2);
+    }
+
+    #[test]
+    fn fit_should_letterbox_a_taller_window() {
+        // Arrange
+        let logical = (800.0, 600.0);
+
+        // Act
+        let viewport = Viewport::fit(logical, (400.0, 600.0));
+
+        // Assert
+        assert_eq!(viewport.scale(), 0.5);
+        assert_eq!(viewport.offset(), Vector::new(0.0, 150.0));
+        assert_eq!(
+            viewport.bars(),
+            vec![[0.0, 0.0, 400.0, 150.0], [0.0, 450.0, 400.0, 150.0]]
+        );
+    }
+
+    #[test]
+    fn bars_should_be_empty_when_aspect_ratio_matches() {
+        // Arrange
+        let viewport = Viewport::fit((800.0, 600.0), (400.0, 300.0));
+
+        // Act
+        let bars = viewport.bars();
+
+        // Assert
+        assert!(bars.is_empty());
+    }
+
+    #[test]
+    fn bar_frame_should_fill_the_bars() {
+        // Arrange
+        let viewport = Viewport::fit((800.0, 600.0), (1600.0, 900.0));
+
+        // Act
+        let frame = viewport.bar_frame();
+
+        // Assert
+        assert_eq!(frame.size(), (1600.0, 900.0));
+        let rects: Vec<Primitive> = viewport
+            .bars()
+            .into_iter()
+            .map(|rect| Primitive::Rect {
+                rect,
+                color: Viewport::BAR_COLOR,
+            })
+            .collect();
+        let primitives: Vec<Primitive> = frame
+            .commands()
+            .iter()
+            .map(|command| command.primitive.clone())
+            .collect();
+        assert_eq!(primitives, rects);
+    }
+
+    #[test]
+    fn to_logical_should_invert_to_physical() {
+        // Arrange
+        let viewport = Viewport::fit((800.0, 600.0), (1600.0, 900.0));
+        let logical = Vector::new(100.0, 200.0);
+
+        // Act
+        let mapped = viewport.to_logical(viewport.to_physical(logical));
+
+        // Assert
+        assert_eq!(mapped, Some(logical));
+    }
+
+    #[test]
+    fn to_logical_should_return_none_inside_the_bars() {
+        // Arrange
+        let viewport = Viewport::fit((800.0, 600.0), (1600.0, 900.0));
+
+        // Act
+        let mapped = viewport.to_logical(Vector::new(50.0, 450.0));
+
+        // Assert
+        assert_eq!(mapped, None);
+    }
+}
@@ -0,0 +1,560 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConfigError;
+use crate::Game;
+
+const SOLO_CAMPAIGN: &str = include_str!("../waves/solo.ron");
+const VERSUS_CAMPAIGN: &str = include_str!("../waves/versus.ron");
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum EnemyKind {
+    #[default]
+    Fighter,
+    Sniper,
+    Spreader,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Entry {
+    Top {
+        #[serde(default)]
+        x: Option<f64>,
+    },
+    Left {
+        #[serde(default)]
+        y: Option<f64>,
+    },
+    Right {
+        #[serde(default)]
+        y: Option<f64>,
+    },
+    Side {
+        #[serde(default)]
+        y: Option<f64>,
+    },
+}
+
+impl Entry {
+    fn position(&self) -> Option<f64> {
+        match *self {
+            Entry::Top { x } => x,
+            Entry::Left { y } | Entry::Right { y } | Entry::Side { y } => y,
+        }
+    }
+}
+
+impl Default for Entry {
+    fn default() -> Entry {
+        Entry::Top { x: None }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Formation {
+    #[default]
+    Single,
+    Row {
+        count: u32,
+        spacing: f64,
+    },
+    Column {
+        count: u32,
+        interval: u32,
+    },
+    Vee {
+        count: u32,
+        spacing: f64,
+        interval: u32,
+    },
+}
+
+impl Formation {
+    pub const MAX_COUNT: u32 = 100;
+    pub const MAX_INTERVAL: u32 = 60 * Game::TICKS_PER_SECOND;
+
+    pub fn count(&self) -> u32 {
+        match *self {
+            Formation::Single => 1,
+            Formation::Row { count, .. }
+            | Formation::Column { count, .. }
+            | Formation::Vee { count, .. } => count,
+        }
+    }
+
+    fn slots(&self) -> Vec<(f64, u32)> {
+        let center = f64::from(self.count().saturating_sub(1)) / 2.0;
+        (0..self.count())
+            .map(|i| {
+                let from_center = f64::from(i) - center;
+                match *self {
+                    Formation::Single => (0.0, 0),
+                    Formation::Row { spacing, .. } => (from_center * spacing, 0),
+                    Formation::Column { interval, .. } => (0.0, i * interval),
+                    Formation::Vee {
+                        spacing, interval, ..
+                    } => (
+                        from_center * spacing,
+                        (from_center.abs() * f64::from(interval)).round() as u32,
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Spawn {
+    pub tick: u32,
+    #[serde(default)]
+    pub kind: EnemyKind,
+    #[serde(default)]
+    pub entry: Entry,
+    #[serde(default)]
+    pub formation: Formation,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum Clear {
+    #[default]
+    NoEnemiesLeft,
+    AfterTicks(u32),
+    Kills(u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Wave {
+    #[serde(default)]
+    pub name: String,
+    pub spawns: Vec<Spawn>,
+    #[serde(default)]
+    pub clear: Clear,
+}
+
+impl Wave {
+    pub const MAX_TICK: u32 = 60 * 60 * Game::TICKS_PER_SECOND;
+
+    fn enemy_count(&self) -> u32 {
+        self.spawns
+            .iter()
+            .map(|spawn| spawn.formation.count())
+            .sum()
+    }
+
+    fn validate(&self, wave: usize) -> Result<(), ConfigError> {
+        let invalid = |reason| Err(ConfigError::Wave { wave, reason });
+
+        for spawn in &self.spawns {
+            if spawn
+                .entry
+                .position()
+                .is_some_and(|position| !(0.0..=1.0).contains(&position))
+            {
+                return invalid("entry positions must be between 0 and 1");
+            }
+            if spawn.tick > Wave::MAX_TICK {
+                return invalid("spawn ticks must not exceed one hour");
+            }
+            if !(1..=Formation::MAX_COUNT).contains(&spawn.formation.count()) {
+                return invalid("formations must have between 1 and 100 enemies");
+            }
+            if let Formation::Column { interval, .. } | Formation::Vee { interval, .. } =
+                spawn.formation
+            {
+                if interval > Formation::MAX_INTERVAL {
+                    return invalid("formation intervals must not exceed one minute");
+                }
+            }
+            if let Formation::Row { spacing, .. } | Formation::Vee { spacing, .. } = spawn.formation
+            {
+                if !spacing.is_finite() || spacing < 0.0 {
+                    return invalid("formation spacing must not be negative");
+                }
+            }
+        }
+
+        match self.clear {
+            Clear::NoEnemiesLeft if self.spawns.is_empty() => {
+                invalid("must spawn an enemy to be cleared")
+            }
+            Clear::AfterTicks(0) => invalid("must last at least one tick"),
+            Clear::Kills(kills) if kills == 0 || kills > self.enemy_count() => {
+                invalid("must require between one kill and the enemies it spawns")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Campaign {
+    pub waves: Vec<Wave>,
+    #[serde(default)]
+    pub loop_from: usize,
+}
+
+impl Campaign {
+    pub fn from_ron(text: &str) -> Result<Campaign, ConfigError> {
+        let campaign: Campaign =
+            ron::from_str(text).map_err(|error| ConfigError::Parse(error.to_string()))?;
+        campaign.validate()?;
+        Ok(campaign)
+    }
+
+    pub fn solo() -> Campaign {
+        Campaign::from_ron(SOLO_CAMPAIGN).expect("Bundled solo campaign must be valid")
+    }
+
+    pub fn versus() -> Campaign {
+        Campaign::from_ron(VERSUS_CAMPAIGN).expect("Bundled versus campaign must be valid")
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.waves.is_empty() {
+            return Err(ConfigError::Invalid {
+                field: "waves",
+                reason: "must not be empty",
+            });
+        }
+        if self.loop_from >= self.waves.len() {
+            return Err(ConfigError::Invalid {
+                field: "loop_from",
+                reason: "must be the index of a wave",
+            });
+        }
+
+        self.waves
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, wave)| wave.validate(i))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Arrival {
+    pub tick: u32,
+    pub kind: EnemyKind,
+    pub entry: Entry,
+    pub offset: f64,
+}
+
+pub struct WaveProgress {
+    wave: usize,
+    ticks: u32,
+    arrivals: Vec<Arrival>,
+    next_arrival: usize,
+    kills_at_start: u32,
+    waves_cleared: u32,
+}
+
+impl WaveProgress {
+    pub fn new(campaign: &Campaign) -> WaveProgress {
+        WaveProgress::starting_at(campaign, 0)
+    }
+
+    pub fn starting_at(campaign: &Campaign, skipped: u32) -> WaveProgress {
+        let wave = (0..skipped).fold(0, |wave, _| next_wave(campaign, wave));
+        let mut progress = WaveProgress {
+            wave,
+            ticks: 0,
+            arrivals: Vec::new(),
+            next_arrival: 0,
+            kills_at_start: 0,
+            waves_cleared: skipped,
+        };
+        progress.start_wave(campaign, wave, 0);
+        progress
+    }
+
+    pub fn waves_cleared(&self) -> u32 {
+        self.waves_cleared
+    }
+
+    pub fn update(&mut self, campaign: &Campaign, kills: u32, enemies_left: bool) -> Vec<Arrival> {
+        let due: Vec<Arrival> = self.arrivals[self.next_arrival..]
+            .iter()
+            .take_while(|arrival| arrival.tick <= self.ticks)
+            .copied()
+            .collect();
+        self.next_arrival += due.len();
+        self.ticks += 1;
+
+        let nothing_left =
+            self.next_arrival == self.arrivals.len() && due.is_empty() && !enemies_left;
+        let cleared = match campaign.waves[self.wave].clear {
+            Clear::NoEnemiesLeft => nothing_left,
+            Clear::AfterTicks(ticks) => self.ticks >= ticks,
+            Clear::Kills(target) => {
+                nothing_left || kills.saturating_sub(self.kills_at_start) >= target
+            }
+        };
+
+        if cleared {
+            self.waves_cleared += 1;
+            self.start_wave(campaign, next_wave(campaign, self.wave), kills);
+        }
+
+        due
+    }
+
+    fn start_wave(&mut self, campaign: &Campaign, wave: usize, kills: u32) {
+        self.wave = wave;
+        self.ticks = 0;
+        self.next_arrival = 0;
+        self.kills_at_start = kills;
+
+        self.arrivals = campaign.waves[wave]
+            .spawns
+            .iter()
+            .flat_map(|spawn| {
+                spawn
+                    .formation
+                    .slots()
+                    .into_iter()
+                    .map(move |(offset, delay)| Arrival {
+                        tick: spawn.tick + delay,
+                        kind: spawn.kind,
+                        entry: spawn.entry,
+                        offset,
+                    })
+            })
+            .collect();
+        self.arrivals.sort_by_key(|arrival| arrival.tick);
+    }
+}
+
+fn next_wave(campaign: &Campaign, wave: usize) -> usize {
+    if wave + 1 < campaign.waves.len() {
+        wave + 1
+    } else {
+        campaign.loop_from
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single(tick: u32) -> Spawn {
+        Spawn {
+            tick,
+            kind: EnemyKind::Fighter,
+            entry: Entry::default(),
+            formation: Formation::Single,
+        }
+    }
+
+    fn campaign(waves: Vec<Wave>) -> Campaign {
+        Campaign {
+            waves,
+            loop_from: 0,
+        }
+    }
+
+    #[test]
+    fn bundled_campaigns_should_be_valid() {
+        // Act
+        let solo = Campaign::from_ron(SOLO_CAMPAIGN);
+        let versus = Campaign::from_ron(VERSUS_CAMPAIGN);
+
+        // Assert
+        assert!(solo.is_ok());
+        assert!(versus.is_ok());
+    }
+
+    #[test]
+    fn from_ron_should_fill_in_optional_fields() {
+        // Arrange
+        let text = "(waves: [(spawns: [(tick: 5, entry: Left(y: Some(0.5)))])])";
+
+        // Act
+        let campaign = Campaign::from_ron(text).unwrap();
+
+        // Assert
+        let spawn = campaign.waves[0].spawns[0];
+        assert_eq!(spawn.kind, EnemyKind::Fighter);
+        assert_eq!(spawn.entry, Entry::Left { y: Some(0.5) });
+        assert_eq!(spawn.formation, Formation::Single);
+        assert_eq!(campaign.waves[0].clear, Clear::NoEnemiesLeft);
+    }
+
+    #[test]
+    fn validate_should_reject_unreachable_kill_targets() {
+        // Arrange
+        let campaign = campaign(vec![Wave {
+            name: String::new(),
+            spawns: vec![single(0)],
+            clear: Clear::Kills(2),
+        }]);
+
+        // Act
+        let result = campaign.validate();
+
+        // Assert
+        assert!(matches!(result, Err(ConfigError::Wave { wave: 0, .. })));
+    }
+
+    #[test]
+    fn validate_should_reject_a_spawn_tick_out_of_range() {
+        // Arrange
+        let campaign = campaign(vec![Wave {
+            name: String::new(),
+            spawns: vec![single(Wave::MAX_TICK + 1)],
+            clear: Clear::NoEnemiesLeft,
+        }]);
+
+        // Act
+        let result = campaign.validate();
+
+        // Assert
+        assert!(matches!(result, Err(ConfigError::Wave { wave: 0, .. })));
+    }
+
+    #[test]
+    fn validate_should_reject_too_large_formations() {
+        // Arrange
+        let mut spawn = single(0);
+        spawn.formation = Formation::Row {
+            count: Formation::MAX_COUNT + 1,
+            spacing: 10.0,
+        };
+        let campaign = campaign(vec![Wave {
+            name: String::new(),
+            spawns: vec![spawn],
+            clear: Clear::NoEnemiesLeft,
+        }]);
+
+        // Act
+        let result = campaign.validate();
+
+        // Assert
+        assert!(matches!(result, Err(ConfigError::Wave { wave: 0, .. })));
+    }
+
+    #[test]
+    fn validate_should_reject_too_long_formation_intervals() {
+        // Arrange
+        let mut spawn = single(0);
+        spawn.formation = Formation::Column {
+            count: 3,
+            interval: Formation::MAX_INTERVAL + 1,
+        };
+        let campaign = campaign(vec![Wave {
+            name: String::new(),
+            spawns: vec![spawn],
+            clear: Clear::NoEnemiesLeft,
+        }]);
+
+        // Act
+        let result = campaign.validate();
+
+        // Assert
+        assert!(matches!(result, Err(ConfigError::Wave { wave: 0, .. })));
+    }
+
+    #[test]
+    fn update_should_spawn_formations_on_their_ticks() {
+        // Arrange
+        let mut spawn = single(2);
+        spawn.formation = Formation::Column {
+            count: 2,
+            interval: 3,
+        };
+        let campaign = campaign(vec![Wave {
+            name: String::new(),
+            spawns: vec![spawn],
+            clear: Clear::NoEnemiesLeft,
+        }]);
+        let mut progress = WaveProgress::new(&campaign);
+
+        // Act
+        let due: Vec<usize> = (0..6)
+            .map(|_| progress.update(&campaign, 0, true).len())
+            .collect();
+
+        // Assert
+        assert_eq!(due, vec![0, 0, 1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn update_should_move_to_the_next_wave_once_cleared() {
+        // Arrange
+        let campaign = campaign(vec![
+            Wave {
+                name: String::from("first"),
+                spawns: vec![single(0)],
+                clear: Clear::NoEnemiesLeft,
+            },
+            Wave {
+                name: String::from("second"),
+                spawns: vec![],
+                clear: Clear::AfterTicks(1),
+            },
+        ]);
+        let mut progress = WaveProgress::new(&campaign);
+        progress.update(&campaign, 0, false);
+
+        // Act
+        progress.update(&campaign, 0, true);
+        let still_first = progress.wave;
+        progress.update(&campaign, 1, false);
+
+        // Assert
+        assert_eq!(still_first, 0);
+        assert_eq!(progress.wave, 1);
+        assert_eq!(progress.waves_cleared(), 1);
+    }
+
+    #[test]
+    fn update_should_loop_back_after_the_last_wave() {
+        // Arrange
+        let mut campaign = campaign(vec![
+            Wave {
+                name: String::new(),
+                spawns: vec![],
+                clear: Clear::AfterTicks(1),
+            },
+            Wave {
+                name: String::new(),
+                spawns: vec![],
+                clear: Clear::AfterTicks(1),
+            },
+        ]);
+        campaign.loop_from = 1;
+        let mut progress = WaveProgress::new(&campaign);
+
+        // Act
+        for _ in 0..3 {
+            progress.update(&campaign, 0, false);
+        }
+
+        // Assert
+        assert_eq!(progress.wave, 1);
+        assert_eq!(progress.waves_cleared(), 3);
+    }
+
+    #[test]
+    fn starting_at_should_skip_waves_and_loop() {
+        // Arrange
+        let mut campaign = campaign(vec![
+            Wave {
+                name: String::new(),
+                spawns: vec![],
+                clear: Clear::AfterTicks(1),
+            },
+            Wave {
+                name: String::new(),
+                spawns: vec![single(3)],
+                clear: Clear::AfterTicks(1),
+            },
+        ]);
+        campaign.loop_from = 1;
+
+        // Act
+        let progress = WaveProgress::starting_at(&campaign, 3);
+
+        // Assert
+        assert_eq!(progress.wave, 1);
+        assert_eq!(progress.waves_cleared(), 3);
+        assert_eq!(progress.arrivals.len(), 1);
+    }
+}
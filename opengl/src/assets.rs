@@ -0,0 +1,142 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use opengl_graphics::{GlyphCache, TextureSettings};
+use spacewars_files::APP_DIRECTORY;
+
+/// Names a directory whose files replace the embedded assets.
+pub const ASSETS_VARIABLE: &str = "SPACEWARS_ASSETS";
+const ASSETS_DIRECTORY: &str = "assets";
+
+/// The files the game needs. Each is embedded in the binary, so that it runs
+/// from any working directory.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Asset {
+    Font,
+}
+
+impl Asset {
+    pub fn file_name(&self) -> &'static str {
+        match *self {
+            Asset::Font => "NovaSquare-Regular.ttf",
+        }
+    }
+
+    fn embedded(&self) -> &'static [u8] {
+        match *self {
+            Asset::Font => include_bytes!("../../assets/NovaSquare-Regular.ttf"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AssetError {
+    Io(PathBuf, io::Error),
+    Invalid(Asset, Option<PathBuf>),
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssetError::Io(path, error) => write!(f, "could not read {:?}: {}", path, error),
+            AssetError::Invalid(asset, Some(path)) => {
+                write!(f, "{:?} is not a valid {:?} asset", path, asset)
+            }
+            AssetError::Invalid(asset, None) => {
+                write!(f, "the embedded {:?} asset is not valid", asset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+/// Looks assets up in an override directory first, then falls back to the
+/// embedded ones.
+pub struct Assets {
+    directory: Option<PathBuf>,
+}
+
+impl Assets {
+    pub fn new(directory: Option<PathBuf>) -> Assets {
+        Assets { directory }
+    }
+
+    /// Overrides come from `$SPACEWARS_ASSETS`, or else from
+    /// `spacewars/assets` in the user config directory.
+    pub fn from_env() -> Assets {
+        let directory = env::var_os(ASSETS_VARIABLE).map(PathBuf::from).or_else(|| {
+            Some(
+                dirs::config_dir()?
+                    .join(APP_DIRECTORY)
+                    .join(ASSETS_DIRECTORY),
+            )
+        });
+        Assets::new(directory)
+    }
+
+    /// The override of an asset, when its file exists.
+    pub fn override_path(&self, asset: Asset) -> Option<PathBuf> {
+        self.directory
+            .as_deref()
+            .map(|directory| directory.join(asset.file_name()))
+            .filter(|path| path.exists())
+    }
+
+    pub fn glyph_cache(
+        &self,
+        settings: TextureSettings,
+    ) -> Result<GlyphCache<'static>, AssetError> {
+        match self.override_path(Asset::Font) {
+            Some(path) => {
+                let font = fs::read(&path).map_err(|error| AssetError::Io(path.clone(), error))?;
+                let font = rusttype::Font::try_from_vec(font)
+                    .ok_or(AssetError::Invalid(Asset::Font, Some(path)))?;
+                Ok(GlyphCache::from_font(font, (), settings))
+            }
+            None => GlyphCache::from_bytes(Asset::Font.embedded(), (), settings)
+                .map_err(|_| AssetError::Invalid(Asset::Font, None)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_cache_should_use_the_embedded_font_without_override() {
+        // Arrange
+        let assets = Assets::new(None);
+
+        // Act
+        let glyphs = assets.glyph_cache(TextureSettings::new());
+
+        // Assert
+        assert!(glyphs.is_ok());
+        assert_eq!(assets.override_path(Asset::Font), None);
+    }
+
+    #[test]
+    fn glyph_cache_should_report_an_invalid_override() {
+        // Arrange
+        let directory = env::temp_dir().join(format!("spacewars-assets-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join(Asset::Font.file_name());
+        fs::write(&path, b"not a font").unwrap();
+        let assets = Assets::new(Some(directory.clone()));
+
+        // Act
+        let glyphs = assets.glyph_cache(TextureSettings::new());
+
+        // Assert
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(matches!(
+            glyphs,
+            Err(AssetError::Invalid(Asset::Font, Some(invalid))) if invalid == path
+        ));
+    }
+}
@@ -3,55 +3,334 @@ extern crate graphics;
 extern crate opengl_graphics;
 extern crate piston;
 
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::Parser;
 use glutin_window::GlutinWindow as Window;
-use graphics::clear;
-use opengl_graphics::{Filter, GlGraphics, GlyphCache, OpenGL, TextureSettings};
+use opengl_graphics::{Filter, GlGraphics, OpenGL, TextureSettings};
 use piston::event_loop::{EventSettings, Events};
-use piston::input::RenderEvent;
-use piston::window::WindowSettings;
+use piston::input::{RenderEvent, UpdateEvent};
+use piston::window::{Window as _, WindowSettings};
 
+use crate::assets::Assets;
 use crate::presenter::Presenter;
-use spacewars_game::Game;
+use crate::screenshot::write_replay;
+use spacewars_files::{
+    high_score_path, load_bindings, load_campaign, load_config, read_config, FileStorage,
+};
+use spacewars_game::{Difficulty, Game, GameConfig, GameMode, Replay};
 
+mod assets;
 mod presenter;
+mod renderer;
+mod screenshot;
 
-const WINDOW_WIDTH: f64 = 800.0;
-const WINDOW_HEIGHT: f64 = 600.0;
+const WINDOW_WIDTH: u32 = 800;
+const WINDOW_HEIGHT: u32 = 600;
+const MIN_WINDOW_SIZE: i64 = 100;
 const FRAMES_PER_SECOND: u64 = 40;
+const MAX_FRAMES_PER_SECOND: u64 = 1000;
+
+/// Plays Space Wars in a window.
+#[derive(Parser, Debug)]
+#[command(name = "spacewars", version)]
+struct Options {
+    /// Window width in pixels.
+    #[arg(long, default_value_t = WINDOW_WIDTH, value_parser = clap::value_parser!(u32).range(MIN_WINDOW_SIZE..))]
+    width: u32,
+
+    /// Window height in pixels.
+    #[arg(long, default_value_t = WINDOW_HEIGHT, value_parser = clap::value_parser!(u32).range(MIN_WINDOW_SIZE..))]
+    height: u32,
+
+    /// Start in fullscreen.
+    #[arg(long)]
+    fullscreen: bool,
+
+    /// Maximum frames drawn per second. The game itself always plays 40
+    /// turns per second.
+    #[arg(long, default_value_t = FRAMES_PER_SECOND, value_parser = clap::value_parser!(u64).range(1..=MAX_FRAMES_PER_SECOND))]
+    fps: u64,
+
+    /// Draw frames without waiting for the vertical sync.
+    #[arg(long)]
+    no_vsync: bool,
+
+    /// Random seed of the session; defaults to the current time.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Configuration file (TOML or RON) to use instead of
+    /// `spacewars/config.toml` in the user config directory.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Difficulty: easy, normal or hard.
+    #[arg(long, default_value = "normal", value_parser = parse_difficulty)]
+    difficulty: Difficulty,
+
+    /// Level games start at, instead of the configured one.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=i64::from(GameConfig::MAX_STARTING_LEVEL)))]
+    level: Option<u32>,
+
+    /// Save the replay of the session to this file when the window closes.
+    #[arg(long)]
+    record: Option<PathBuf>,
+
+    /// Watch a replay file, as saved with F11 or `--record`, instead of
+    /// playing. It brings its own seed and settings.
+    #[arg(long, conflicts_with_all = ["seed", "config", "difficulty", "level", "record"])]
+    replay: Option<PathBuf>,
+}
+
+fn parse_difficulty(name: &str) -> Result<Difficulty, String> {
+    Difficulty::from_name(name).ok_or_else(|| format!("unknown difficulty '{}'", name))
+}
+
+fn exit_with_error(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn load_replay(path: &Path) -> Replay {
+    let replay = fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|text| Replay::from_ron(&text).map_err(|error| error.to_string()));
+
+    replay.unwrap_or_else(|error| {
+        exit_with_error(format!("Could not load replay {:?}: {}", path, error))
+    })
+}
+
+/// A game played from the keyboard, recorded as a replay with the settings
+/// it was created with.
+fn new_session(options: &Options) -> (Game, Replay) {
+    let seed = options.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    });
+
+    let config = match &options.config {
+        Some(path) => read_config(path).unwrap_or_else(|error| {
+            exit_with_error(format!("Could not load {:?}: {}", path, error))
+        }),
+        None => load_config(),
+    };
+    let mut config = options.difficulty.apply(config);
+    if let Some(level) = options.level {
+        config.starting_level = level;
+    }
+
+    let campaign = load_campaign();
+    let storage = FileStorage::new(high_score_path());
+    let mut game = Game::with_config(config, Box::new(storage))
+        .unwrap_or_else(|error| exit_with_error(error.to_string()));
+    game.seed_random(seed);
+    if let Some(campaign) = &campaign {
+        for mode in [GameMode::Solo, GameMode::Coop] {
+            game.set_campaign(mode, campaign.clone())
+                .expect("Campaign is validated when loaded");
+        }
+    }
+
+    let high_scores = game.high_scores().to_vec();
+    (game, Replay::new(seed, config, campaign, high_scores))
+}
 
 pub fn main() {
+    let options = Options::parse();
+
+    let (game, replay, playback) = match &options.replay {
+        Some(path) => {
+            let playback = load_replay(path);
+            let game = playback
+                .start()
+                .expect("Replay settings are validated when loaded");
+            let replay = Replay::new(
+                playback.seed,
+                playback.config,
+                playback.campaign.clone(),
+                playback.high_scores.clone(),
+            );
+            (game, replay, Some(playback))
+        }
+        None => {
+            let (game, replay) = new_session(&options);
+            (game, replay, None)
+        }
+    };
+
     let opengl = OpenGL::V3_2;
 
-    let mut window: Window = WindowSettings::new("Space Wars", [WINDOW_WIDTH, WINDOW_HEIGHT])
+    let mut window: Window = WindowSettings::new("Space Wars", [options.width, options.height])
         .graphics_api(opengl)
-        .fullscreen(false)
-        .vsync(true)
+        .fullscreen(options.fullscreen)
+        .vsync(!options.no_vsync)
         .exit_on_esc(true)
+        .resizable(true)
         .build()
-        .unwrap();
+        .unwrap_or_else(|error| exit_with_error(format!("Could not open a window: {}", error)));
 
     let mut gl = GlGraphics::new(opengl);
 
-    let game = Game::new(WINDOW_WIDTH, WINDOW_HEIGHT);
-    let mut presenter = Presenter::new(game);
+    let window_size = window.size();
+    let mut presenter = Presenter::new(
+        game,
+        load_bindings(),
+        replay,
+        (window_size.width, window_size.height),
+    );
+    if let Some(playback) = playback {
+        presenter.watch(playback);
+    }
 
     let mut event_settings = EventSettings::new();
-    event_settings.max_fps = FRAMES_PER_SECOND;
+    event_settings.max_fps = options.fps;
+    event_settings.ups = u64::from(Game::TICKS_PER_SECOND);
 
     let mut events = Events::new(event_settings);
 
     let texture_settings = TextureSettings::new().filter(Filter::Nearest);
-    let glyphs = &mut GlyphCache::new("./assets/NovaSquare-Regular.ttf", (), texture_settings)
-        .expect("Could not load font");
+    let glyphs = &mut Assets::from_env()
+        .glyph_cache(texture_settings)
+        .unwrap_or_else(|error| {
+            exit_with_error(format!("Could not load the game font: {}", error))
+        });
 
     while let Some(e) = events.next(&mut window) {
         presenter.event(&e);
 
+        if e.update_args().is_some() {
+            presenter.update();
+        }
+
         if let Some(args) = e.render_args() {
             gl.draw(args.viewport(), |c, g| {
-                clear([0.0; 4], g);
                 presenter.render(c, g, glyphs);
             });
         }
     }
+
+    if let Some(path) = &options.record {
+        match write_replay(presenter.replay(), path) {
+            Ok(()) => println!("Saved replay to {:?}", path),
+            Err(error) => eprintln!("Could not save replay to {:?}: {}", path, error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::error::ErrorKind;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, clap::Error> {
+        Options::try_parse_from(["spacewars"].iter().chain(args))
+    }
+
+    fn error_kind(args: &[&str]) -> Option<ErrorKind> {
+        parse(args).err().map(|error| error.kind())
+    }
+
+    #[test]
+    fn try_parse_from_should_use_the_defaults() {
+        // Arrange
+        let args = [];
+
+        // Act
+        let options = parse(&args).unwrap();
+
+        // Assert
+        assert_eq!(options.width, WINDOW_WIDTH);
+        assert_eq!(options.height, WINDOW_HEIGHT);
+        assert_eq!(options.fps, FRAMES_PER_SECOND);
+        assert_eq!(options.difficulty, Difficulty::Normal);
+        assert_eq!(options.level, None);
+    }
+
+    #[test]
+    fn try_parse_from_should_reject_a_window_smaller_than_the_minimum() {
+        // Arrange
+        let width = ["--width", "99"];
+        let height = ["--height", "99"];
+
+        // Act
+        let width_error = error_kind(&width);
+        let height_error = error_kind(&height);
+
+        // Assert
+        assert_eq!(width_error, Some(ErrorKind::ValueValidation));
+        assert_eq!(height_error, Some(ErrorKind::ValueValidation));
+        assert!(parse(&["--width", "100", "--height", "100"]).is_ok());
+    }
+
+    #[test]
+    fn try_parse_from_should_reject_fps_out_of_range() {
+        // Arrange
+        let zero = ["--fps", "0"];
+        let too_many = ["--fps", "1001"];
+
+        // Act
+        let zero_error = error_kind(&zero);
+        let too_many_error = error_kind(&too_many);
+
+        // Assert
+        assert_eq!(zero_error, Some(ErrorKind::ValueValidation));
+        assert_eq!(too_many_error, Some(ErrorKind::ValueValidation));
+        assert!(parse(&["--fps", "1000"]).is_ok());
+    }
+
+    #[test]
+    fn try_parse_from_should_reject_a_level_out_of_range() {
+        // Arrange
+        let zero = ["--level", "0"];
+        let too_high = ["--level", "100"];
+
+        // Act
+        let zero_error = error_kind(&zero);
+        let too_high_error = error_kind(&too_high);
+
+        // Assert
+        assert_eq!(zero_error, Some(ErrorKind::ValueValidation));
+        assert_eq!(too_high_error, Some(ErrorKind::ValueValidation));
+        assert_eq!(parse(&["--level", "99"]).unwrap().level, Some(99));
+    }
+
+    #[test]
+    fn try_parse_from_should_reject_settings_with_a_replay() {
+        // Arrange
+        let settings = [
+            ["--seed", "7"],
+            ["--config", "config.toml"],
+            ["--difficulty", "hard"],
+            ["--level", "3"],
+        ];
+
+        // Act
+        let errors: Vec<Option<ErrorKind>> = settings
+            .iter()
+            .map(|setting| error_kind(&["--replay", "replay.ron", setting[0], setting[1]]))
+            .collect();
+
+        // Assert
+        assert_eq!(errors, vec![Some(ErrorKind::ArgumentConflict); 4]);
+        assert!(parse(&["--replay", "replay.ron"]).is_ok());
+    }
+
+    #[test]
+    fn try_parse_from_should_refuse_to_record_a_replay() {
+        // Arrange
+        let args = ["--replay", "replay.ron", "--record", "copy.ron"];
+
+        // Act
+        let error = error_kind(&args);
+
+        // Assert
+        assert_eq!(error, Some(ErrorKind::ArgumentConflict));
+    }
 }
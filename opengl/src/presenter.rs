@@ -1,70 +1,315 @@
-use graphics::{text, Context, Polygon, Transformed};
+use std::collections::HashSet;
+use std::thread::{self, JoinHandle};
+
+use graphics::{Context, Transformed};
 use opengl_graphics::{GlGraphics, GlyphCache};
 use piston::input::{Button, GenericEvent, Key, MouseButton};
 
-use spacewars_game::{Direction, Game};
+use crate::renderer::{draw_commands, draw_frame};
+use crate::screenshot::{save_gif, save_replay, save_svg};
+use spacewars_files::save_bindings;
+use spacewars_game::{
+    frame_to_svg, Anchor, Game, KeyBindings, Layer, PlayerId, Position, Primitive, Rebinding,
+    RenderFrame, Replay, ReplayInput, ScreenSize, Vector, Viewport,
+};
+use spacewars_raster::Recorder;
+
+const NOTICE_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+const NOTICE_BOTTOM_PADDING: f64 = 20.0;
+const NOTICE_TICKS: u32 = 2 * Game::TICKS_PER_SECOND;
+
+const REBIND_KEY: Key = Key::F2;
+const SCREENSHOT_KEY: Key = Key::F9;
+const RECORD_KEY: Key = Key::F10;
+const SAVE_REPLAY_KEY: Key = Key::F11;
+
+/// Recordings are saved at half the game size, which is enough for docs and
+/// bug reports and keeps the GIF files small.
+const RECORDING_SCALE: f32 = 0.5;
 
 pub struct Presenter {
     game: Game,
+    bindings: KeyBindings,
+    rebinding: Option<Rebinding>,
+    replay: Replay,
+    playback: Option<Replay>,
+    recorder: Option<Recorder>,
+    encoders: Vec<JoinHandle<String>>,
+    viewport: Viewport,
+    cursor: Option<Position>,
+    held_keys: HashSet<Key>,
+    notice: Option<(String, u32)>,
 }
 
 impl Presenter {
-    pub fn new(game: Game) -> Presenter {
-        Presenter { game }
+    /// `replay` must hold the seed and settings `game` was created with, so
+    /// that the inputs recorded in it play the same session again.
+    pub fn new(
+        game: Game,
+        bindings: KeyBindings,
+        replay: Replay,
+        window_size: ScreenSize,
+    ) -> Presenter {
+        let viewport = Viewport::fit(game.screen_size(), window_size);
+        Presenter {
+            game,
+            bindings,
+            rebinding: None,
+            replay,
+            playback: None,
+            recorder: None,
+            encoders: Vec::new(),
+            viewport,
+            cursor: None,
+            held_keys: HashSet::new(),
+            notice: None,
+        }
     }
 
-    pub fn render(&mut self, context: Context, graphics: &mut GlGraphics, glyphs: &mut GlyphCache) {
+    /// Plays the inputs of `playback` instead of the player ones. `game` must
+    /// have been started from it.
+    pub fn watch(&mut self, playback: Replay) {
+        self.playback = Some(playback);
+    }
+
+    /// The session so far, with the inputs played back when watching a replay.
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Plays one turn. Turns run at a fixed rate, whatever the frame rate.
+    pub fn update(&mut self) {
+        if let Some((_, ticks)) = self.notice.as_mut() {
+            *ticks -= 1;
+            if *ticks == 0 {
+                self.notice = None;
+            }
+        }
+        self.collect_encoders();
+
+        if self.rebinding.is_some() {
+            return;
+        }
+
+        if let Some(playback) = &self.playback {
+            let inputs: Vec<ReplayInput> = playback.inputs_at(self.replay.ticks).collect();
+            for input in inputs {
+                self.input(input);
+            }
+        }
+
         self.game.next_turn();
+        self.replay.next_turn();
 
-        for object in self.game.space_objects().iter() {
-            Polygon::new(object.color()).draw(
-                &object.coord(),
-                &context.draw_state,
-                context.transform,
-                graphics,
-            );
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.capture(self.game.render_frame());
         }
+    }
+
+    pub fn render(&mut self, context: Context, graphics: &mut GlGraphics, glyphs: &mut GlyphCache) {
+        let offset = self.viewport.offset();
+        let scale = self.viewport.scale();
+        let game_context = context.trans(offset.x, offset.y).scale(scale, scale);
 
-        for text in self.game.texts().iter() {
-            let (position_x, position_y) = text.position();
-            text::Text::new_color(text.color(), text.font_size())
-                .draw(
-                    &text.content(),
-                    glyphs,
-                    &context.draw_state,
-                    context.trans(position_x, position_y).transform,
-                    graphics,
-                )
-                .unwrap();
+        if let Some(rebinding) = &self.rebinding {
+            let frame = rebinding.render_frame(self.game.screen_size());
+            draw_frame(&frame, game_context, graphics, glyphs);
+            return;
         }
+
+        let mut frame = self.game.render_frame();
+        self.push_notice(&mut frame);
+        draw_frame(&frame, game_context, graphics, glyphs);
+
+        draw_commands(&self.viewport.bar_frame(), context, graphics, glyphs);
     }
 
     pub fn event<E: GenericEvent>(&mut self, e: &E) {
+        if let Some(args) = e.resize_args() {
+            let [width, height] = args.window_size;
+            self.viewport = Viewport::fit(self.game.screen_size(), (width, height));
+        }
+
+        if let Some([x, y]) = e.mouse_cursor_args() {
+            self.cursor = self.viewport.to_logical(Vector::new(x, y));
+        }
+
         if let Some(Button::Mouse(MouseButton::Left)) = e.press_args() {
-            self.fire_attack();
+            if self.cursor.is_some() && self.playback.is_none() {
+                self.fire_attack(Game::PLAYER_ONE);
+            }
         }
 
         if let Some(Button::Keyboard(key)) = e.press_args() {
-            self.key_pressed();
+            let repeat = !self.held_keys.insert(key);
+            self.key_pressed(key, repeat);
+        }
 
-            match key {
-                Key::Right => self.move_player(Direction::RIGHT),
-                Key::Left => self.move_player(Direction::LEFT),
-                Key::Space => self.fire_attack(),
-                _ => {}
+        if let Some(Button::Keyboard(key)) = e.release_args() {
+            self.held_keys.remove(&key);
+        }
+    }
+
+    /// `repeat` is set for the presses piston sends while a key is held.
+    fn key_pressed(&mut self, key: Key, repeat: bool) {
+        if repeat && !KeyBindings::accepts_repeat(&key_name(key), self.rebinding.is_some()) {
+            return;
+        }
+
+        if let Some(rebinding) = self.rebinding.as_mut() {
+            rebinding.press(&key_name(key));
+            if rebinding.is_cancelled() {
+                self.rebinding = None;
+            } else if rebinding.is_done() {
+                self.finish_rebinding();
+            }
+            return;
+        }
+
+        match key {
+            REBIND_KEY => self.rebinding = Some(Rebinding::new(self.bindings.clone())),
+            SCREENSHOT_KEY => self.screenshot(),
+            RECORD_KEY => self.toggle_recording(),
+            SAVE_REPLAY_KEY => self.save_replay(),
+            _ if self.playback.is_some() => {}
+            _ => {
+                let player_count = self.game.mode().player_count();
+                for input in self.bindings.inputs(&key_name(key), player_count) {
+                    self.input(input);
+                }
+            }
+        }
+    }
+
+    fn finish_rebinding(&mut self) {
+        if let Some(rebinding) = self.rebinding.take() {
+            self.bindings = rebinding.bindings().clone();
+            let notice = match save_bindings(&self.bindings) {
+                Ok(path) => format!("Saved key bindings to {}", path.display()),
+                Err(error) => format!("Could not save key bindings: {}", error),
+            };
+            self.notify(notice);
+        }
+    }
+
+    /// Sends an input to the game and keeps it in the session replay.
+    fn input(&mut self, input: ReplayInput) {
+        self.replay.record(input);
+        input.apply(&mut self.game);
+    }
+
+    pub fn fire_attack(&mut self, player: PlayerId) {
+        self.input(ReplayInput::Fire(player));
+    }
+
+    pub fn screenshot(&mut self) {
+        let notice = match save_svg(&frame_to_svg(&self.game.render_frame())) {
+            Ok(path) => format!("Saved screenshot to {}", path.display()),
+            Err(error) => format!("Could not save screenshot: {}", error),
+        };
+        self.notify(notice);
+    }
+
+    /// Shows a message over the game for a little while. It stays out of
+    /// screenshots and recordings.
+    fn notify(&mut self, message: String) {
+        self.notice = Some((message, NOTICE_TICKS));
+    }
+
+    fn push_notice(&self, frame: &mut RenderFrame) {
+        let Some((message, _)) = &self.notice else {
+            return;
+        };
+
+        let (width, height) = frame.size();
+        frame.push(
+            Layer::Hud,
+            Primitive::Text {
+                content: message.clone(),
+                position: Vector::new(width / 2.0, height - NOTICE_BOTTOM_PADDING),
+                font_size: Game::HIGH_SCORE_TEXT_SIZE,
+                anchor: Anchor::Center,
+                color: NOTICE_COLOR,
+            },
+        );
+    }
+
+    /// Starts capturing frames, or stops and encodes them to a GIF in the
+    /// background so that the game keeps running.
+    pub fn toggle_recording(&mut self) {
+        match self.recorder.take() {
+            None => {
+                self.recorder = Some(Recorder::new(RECORDING_SCALE));
+                self.notify(String::from("Recording started"));
+            }
+            Some(recorder) if recorder.is_empty() => {
+                self.notify(String::from("Nothing was recorded"));
+            }
+            Some(recorder) => {
+                self.notify(format!("Saving {} recorded frames", recorder.len()));
+                self.encoders
+                    .push(thread::spawn(move || match save_gif(&recorder) {
+                        Ok(path) => format!("Saved recording to {}", path.display()),
+                        Err(error) => format!("Could not save recording: {}", error),
+                    }));
             }
         }
     }
 
-    pub fn move_player(&mut self, direction: Direction) {
-        self.game.move_player(direction);
+    /// Shows the outcome of the recordings done encoding.
+    fn collect_encoders(&mut self) {
+        let (finished, running): (Vec<JoinHandle<String>>, Vec<JoinHandle<String>>) = self
+            .encoders
+            .drain(..)
+            .partition(|encoder| encoder.is_finished());
+        self.encoders = running;
+
+        for encoder in finished {
+            let notice = encoder
+                .join()
+                .unwrap_or_else(|_| String::from("Could not save recording"));
+            self.notify(notice);
+        }
+    }
+
+    pub fn save_replay(&mut self) {
+        let notice = match save_replay(&self.replay) {
+            Ok(path) => format!("Saved replay to {}", path.display()),
+            Err(error) => format!("Could not save replay: {}", error),
+        };
+        self.notify(notice);
     }
+}
 
-    pub fn fire_attack(&mut self) {
-        self.game.fire_player_attack();
+/// Names piston keys as the shared key bindings do.
+fn key_name(key: Key) -> String {
+    match key {
+        Key::Return | Key::Return2 | Key::NumPadEnter => String::from("Enter"),
+        Key::LShift | Key::RShift => String::from("Shift"),
+        Key::LCtrl | Key::RCtrl => String::from("Ctrl"),
+        Key::LAlt | Key::RAlt => String::from("Alt"),
+        Key::D0
+        | Key::D1
+        | Key::D2
+        | Key::D3
+        | Key::D4
+        | Key::D5
+        | Key::D6
+        | Key::D7
+        | Key::D8
+        | Key::D9 => format!("{:?}", key).replace('D', ""),
+        _ => format!("{:?}", key),
     }
+}
 
-    pub fn key_pressed(&mut self) {
-        self.game.key_pressed()
+impl Drop for Presenter {
+    /// Waits for the recordings still being encoded when the window closes,
+    /// reporting them on the terminal since the window is gone.
+    fn drop(&mut self) {
+        for encoder in self.encoders.drain(..) {
+            if let Ok(notice) = encoder.join() {
+                println!("{}", notice);
+            }
+        }
     }
 }
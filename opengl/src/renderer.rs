@@ -0,0 +1,90 @@
+use graphics::character::CharacterCache;
+use graphics::{clear, ellipse, line, rectangle, text, Context, Polygon, Transformed};
+use opengl_graphics::{GlGraphics, GlyphCache};
+
+use spacewars_game::{Anchor, Primitive, RenderFrame};
+
+pub fn draw_frame(
+    frame: &RenderFrame,
+    context: Context,
+    graphics: &mut GlGraphics,
+    glyphs: &mut GlyphCache,
+) {
+    clear(frame.background(), graphics);
+    draw_commands(frame, context, graphics, glyphs);
+}
+
+/// Draws over what is already on screen, without clearing it first.
+pub fn draw_commands(
+    frame: &RenderFrame,
+    context: Context,
+    graphics: &mut GlGraphics,
+    glyphs: &mut GlyphCache,
+) {
+    for command in frame.commands() {
+        draw_primitive(&command.primitive, context, graphics, glyphs);
+    }
+}
+
+fn draw_primitive(
+    primitive: &Primitive,
+    context: Context,
+    graphics: &mut GlGraphics,
+    glyphs: &mut GlyphCache,
+) {
+    match primitive {
+        Primitive::FillPolygon { points, color } => {
+            Polygon::new(*color).draw(points, &context.draw_state, context.transform, graphics);
+        }
+        Primitive::StrokePolygon {
+            points,
+            color,
+            width,
+        } => {
+            for (i, [x, y]) in points.iter().enumerate() {
+                let [next_x, next_y] = points[(i + 1) % points.len()];
+                line(
+                    *color,
+                    width / 2.0,
+                    [*x, *y, next_x, next_y],
+                    context.transform,
+                    graphics,
+                );
+            }
+        }
+        Primitive::Rect { rect, color } => {
+            rectangle(*color, *rect, context.transform, graphics);
+        }
+        Primitive::Circle {
+            center,
+            radius,
+            color,
+        } => {
+            let bounds = ellipse::circle(center.x, center.y, *radius);
+            ellipse(*color, bounds, context.transform, graphics);
+        }
+        Primitive::Text {
+            content,
+            position,
+            font_size,
+            anchor,
+            color,
+        } => {
+            let width = glyphs.width(*font_size, content).unwrap_or(0.0);
+            let x = match anchor {
+                Anchor::Left => position.x,
+                Anchor::Center => position.x - width / 2.0,
+                Anchor::Right => position.x - width,
+            };
+            text::Text::new_color(*color, *font_size)
+                .draw(
+                    content,
+                    glyphs,
+                    &context.draw_state,
+                    context.trans(x, position.y).transform,
+                    graphics,
+                )
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use spacewars_game::Replay;
+use spacewars_raster::{RecordError, Recorder};
+
+/// Screenshots, recordings and replays go to the user picture directory, or the working directory
+/// when there is none.
+pub fn screenshot_path(extension: &str) -> PathBuf {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+
+    dirs::picture_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(format!("spacewars-{}.{}", millis, extension))
+}
+
+fn new_screenshot(extension: &str) -> io::Result<PathBuf> {
+    let path = screenshot_path(extension);
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    Ok(path)
+}
+
+pub fn save_svg(svg: &str) -> io::Result<PathBuf> {
+    let path = new_screenshot("svg")?;
+    fs::write(&path, svg)?;
+
+    Ok(path)
+}
+
+pub fn save_gif(recorder: &Recorder) -> Result<PathBuf, RecordError> {
+    let path = new_screenshot("gif")?;
+    recorder.save_gif(&path)?;
+
+    Ok(path)
+}
+
+pub fn save_replay(replay: &Replay) -> io::Result<PathBuf> {
+    let path = new_screenshot("ron")?;
+    write_replay(replay, &path)?;
+
+    Ok(path)
+}
+
+pub fn write_replay(replay: &Replay, path: &Path) -> io::Result<()> {
+    fs::write(path, replay.to_ron())
+}
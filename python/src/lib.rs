@@ -0,0 +1,70 @@
+#![cfg(feature = "pyo3")]
+
+use std::collections::HashMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use spacewars_game::{Action, Env, Observation, StepInfo};
+
+type PyObservation = (Vec<f32>, Vec<f32>);
+type PyInfo = HashMap<&'static str, u64>;
+
+#[pyclass(name = "Env", unsendable)]
+struct PyEnv {
+    env: Env,
+}
+
+#[pymethods]
+impl PyEnv {
+    #[new]
+    fn new() -> PyEnv {
+        PyEnv { env: Env::new() }
+    }
+
+    #[classattr]
+    fn action_count() -> usize {
+        Action::ALL.len()
+    }
+
+    #[classattr]
+    fn feature_count() -> usize {
+        Env::FEATURE_COUNT
+    }
+
+    #[classattr]
+    fn grid_shape() -> (usize, usize) {
+        (Env::GRID_HEIGHT, Env::GRID_WIDTH)
+    }
+
+    fn reset(&mut self, seed: u64) -> PyObservation {
+        observation(self.env.reset(seed))
+    }
+
+    fn step(&mut self, action: usize) -> PyResult<(PyObservation, f64, bool, PyInfo)> {
+        let action = Action::from_index(action)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown action {}", action)))?;
+
+        let (next, reward, done, step_info) = self.env.step(action);
+        Ok((observation(next), reward, done, info(step_info)))
+    }
+}
+
+fn observation(observation: Observation) -> PyObservation {
+    (observation.features, observation.grid)
+}
+
+fn info(info: StepInfo) -> PyInfo {
+    HashMap::from([
+        ("score", u64::from(info.score)),
+        ("lives", u64::from(info.lives)),
+        ("kills", u64::from(info.kills)),
+        ("shots_fired", u64::from(info.shots_fired)),
+        ("ticks", info.ticks),
+    ])
+}
+
+#[pymodule]
+fn spacewars(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEnv>()
+}
@@ -0,0 +1,398 @@
+use std::fmt;
+
+use rusttype::{point, Font, Scale};
+use spacewars_game::{Anchor, Color, Position, Primitive, RenderFrame};
+use tiny_skia::{FillRule, Paint, PathBuilder, PremultipliedColorU8, Rect, Stroke, Transform};
+
+pub use crate::recorder::{RecordError, Recorder};
+pub use tiny_skia::Pixmap;
+
+mod recorder;
+
+const FONT: &[u8] = include_bytes!("../../assets/NovaSquare-Regular.ttf");
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DrawError {
+    Size { width: u32, height: u32 },
+}
+
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DrawError::Size { width, height } => {
+                write!(f, "cannot draw a {}x{} image", width, height)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DrawError {}
+
+/// Draws game frames into RGBA images on the CPU, so that frames can be
+/// saved or compared without a GPU or a window.
+pub struct Rasterizer {
+    font: Font<'static>,
+    scale: f32,
+}
+
+impl Rasterizer {
+    /// Frames size their texts in points, which are a third larger than
+    /// pixels at 96 DPI.
+    pub const POINTS_TO_PIXELS: f32 = 1.333;
+
+    pub fn new(scale: f32) -> Rasterizer {
+        Rasterizer {
+            font: Font::try_from_bytes(FONT).expect("Bundled font must be valid"),
+            scale,
+        }
+    }
+
+    pub fn draw(&self, frame: &RenderFrame) -> Result<Pixmap, DrawError> {
+        let (width, height) = frame.size();
+        let width = (width as f32 * self.scale).round() as u32;
+        let height = (height as f32 * self.scale).round() as u32;
+        let mut pixmap = Pixmap::new(width, height).ok_or(DrawError::Size { width, height })?;
+        pixmap.fill(skia_color(frame.background()));
+
+        for command in frame.commands() {
+            self.draw_primitive(&mut pixmap, &command.primitive);
+        }
+
+        Ok(pixmap)
+    }
+
+    fn draw_primitive(&self, pixmap: &mut Pixmap, primitive: &Primitive) {
+        let transform = Transform::from_scale(self.scale, self.scale);
+
+        match primitive {
+            Primitive::FillPolygon { points, color } => {
+                if let Some(path) = polygon_path(points) {
+                    pixmap.fill_path(&path, &paint(*color), FillRule::Winding, transform, None);
+                }
+            }
+            Primitive::StrokePolygon {
+                points,
+                color,
+                width,
+            } => {
+                if let Some(path) = polygon_path(points) {
+                    let stroke = Stroke {
+                        width: *width as f32,
+                        ..Stroke::default()
+                    };
+                    pixmap.stroke_path(&path, &paint(*color), &stroke, transform, None);
+                }
+            }
+            Primitive::Rect { rect, color } => {
+                let [x, y, width, height] = rect.map(|value| value as f32);
+                if let Some(rect) = Rect::from_xywh(x, y, width, height) {
+                    pixmap.fill_rect(rect, &paint(*color), transform, None);
+                }
+            }
+            Primitive::Circle {
+                center,
+                radius,
+                color,
+            } => {
+                let circle =
+                    PathBuilder::from_circle(center.x as f32, center.y as f32, *radius as f32);
+                if let Some(path) = circle {
+                    pixmap.fill_path(&path, &paint(*color), FillRule::Winding, transform, None);
+                }
+            }
+            Primitive::Text {
+                content,
+                position,
+                font_size,
+                anchor,
+                color,
+            } => self.draw_text(pixmap, content, *position, *font_size, *anchor, *color),
+        }
+    }
+
+    fn draw_text(
+        &self,
+        pixmap: &mut Pixmap,
+        content: &str,
+        position: Position,
+        font_size: u32,
+        anchor: Anchor,
+        color: Color,
+    ) {
+        let scale =
+            Scale::uniform((font_size as f32 * Rasterizer::POINTS_TO_PIXELS).round() * self.scale);
+        let width = self
+            .font
+            .layout(content, scale, point(0.0, 0.0))
+            .last()
+            .map_or(0.0, |glyph| {
+                glyph.position().x + glyph.unpositioned().h_metrics().advance_width
+            });
+        let x = position.x as f32 * self.scale;
+        let start = match anchor {
+            Anchor::Left => x,
+            Anchor::Center => x - width / 2.0,
+            Anchor::Right => x - width,
+        };
+
+        let origin = point(start, position.y as f32 * self.scale);
+        for glyph in self.font.layout(content, scale, origin) {
+            if let Some(bounds) = glyph.pixel_bounding_box() {
+                glyph.draw(|x, y, coverage| {
+                    let x = bounds.min.x + x as i32;
+                    let y = bounds.min.y + y as i32;
+                    blend(pixmap, x, y, color, coverage);
+                });
+            }
+        }
+    }
+}
+
+impl Default for Rasterizer {
+    fn default() -> Rasterizer {
+        Rasterizer::new(1.0)
+    }
+}
+
+fn skia_color(color: Color) -> tiny_skia::Color {
+    let [red, green, blue, alpha] = color.map(|channel| channel.clamp(0.0, 1.0));
+    tiny_skia::Color::from_rgba(red, green, blue, alpha).unwrap_or(tiny_skia::Color::BLACK)
+}
+
+fn paint(color: Color) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color(skia_color(color));
+    paint.anti_alias = true;
+    paint
+}
+
+fn polygon_path(points: &[[f64; 2]]) -> Option<tiny_skia::Path> {
+    let mut builder = PathBuilder::new();
+    for (i, [x, y]) in points.iter().enumerate() {
+        if i == 0 {
+            builder.move_to(*x as f32, *y as f32);
+        } else {
+            builder.line_to(*x as f32, *y as f32);
+        }
+    }
+    builder.close();
+    builder.finish()
+}
+
+/// Draws `color` over a pixel with the given glyph coverage.
+fn blend(pixmap: &mut Pixmap, x: i32, y: i32, color: Color, coverage: f32) {
+    let (width, height) = (pixmap.width() as i32, pixmap.height() as i32);
+    if x < 0 || y < 0 || x >= width || y >= height {
+        return;
+    }
+
+    let [red, green, blue, alpha] = color.map(|channel| channel.clamp(0.0, 1.0));
+    let source_alpha = alpha * coverage.clamp(0.0, 1.0);
+    let index = (y * width + x) as usize;
+    let pixel = pixmap.pixels()[index];
+
+    let mix = |source: f32, target: u8| {
+        (source * source_alpha * 255.0 + f32::from(target) * (1.0 - source_alpha)).round() as u8
+    };
+    let blended_alpha = mix(1.0, pixel.alpha());
+    let channel = |source: f32, target: u8| mix(source, target).min(blended_alpha);
+
+    if let Some(blended) = PremultipliedColorU8::from_rgba(
+        channel(red, pixel.red()),
+        channel(green, pixel.green()),
+        channel(blue, pixel.blue()),
+        blended_alpha,
+    ) {
+        pixmap.pixels_mut()[index] = blended;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use spacewars_game::{Autopilot, Game, GameMode, Layer, MemoryStorage};
+
+    const CHANNEL_TOLERANCE: u8 = 8;
+    const PIXEL_TOLERANCE: usize = 100;
+
+    fn seeded_game(seed: u64) -> Game {
+        let mut game = Game::new(
+            Game::LOGICAL_WIDTH,
+            Game::LOGICAL_HEIGHT,
+            Box::new(MemoryStorage::default()),
+        )
+        .expect("The logical screen size is valid");
+        game.seed_random(seed);
+        game
+    }
+
+    /// Compares against `golden/<name>`; run with `UPDATE_GOLDEN=1` to
+    /// regenerate the images after an intended visual change.
+    fn assert_matches_golden(pixmap: &Pixmap, name: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("golden")
+            .join(name);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            pixmap.save_png(&path).expect("Could not save golden image");
+            return;
+        }
+
+        let expected = Pixmap::load_png(&path).expect("Missing golden image");
+        assert_eq!(
+            (pixmap.width(), pixmap.height()),
+            (expected.width(), expected.height())
+        );
+        let different = pixmap
+            .data()
+            .chunks(4)
+            .zip(expected.data().chunks(4))
+            .filter(|(actual, expected)| {
+                actual
+                    .iter()
+                    .zip(expected.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > CHANNEL_TOLERANCE)
+            })
+            .count();
+        assert!(
+            different <= PIXEL_TOLERANCE,
+            "{} differs from the golden image in {} pixels",
+            name,
+            different
+        );
+    }
+
+    #[test]
+    fn draw_should_scale_the_frame() {
+        // Arrange
+        let frame = RenderFrame::new((100.0, 50.0));
+
+        // Act
+        let pixmap = Rasterizer::new(2.0).draw(&frame).unwrap();
+
+        // Assert
+        assert_eq!((pixmap.width(), pixmap.height()), (200, 100));
+        assert_eq!(pixmap.pixel(0, 0).unwrap().alpha(), 255);
+    }
+
+    #[test]
+    fn draw_should_reject_a_frame_scaled_to_nothing() {
+        // Arrange
+        let frame = RenderFrame::new((100.0, 50.0));
+
+        // Act
+        let result = Rasterizer::new(0.001).draw(&frame);
+
+        // Assert
+        assert_eq!(
+            result.err(),
+            Some(DrawError::Size {
+                width: 0,
+                height: 0
+            })
+        );
+    }
+
+    #[test]
+    fn draw_should_fill_polygons_and_texts() {
+        // Arrange
+        let mut frame = RenderFrame::new((100.0, 100.0));
+        frame.push(
+            Layer::Heroes,
+            Primitive::FillPolygon {
+                points: vec![[10.0, 10.0], [30.0, 10.0], [30.0, 30.0], [10.0, 30.0]],
+                color: [1.0, 0.0, 0.0, 1.0],
+            },
+        );
+        frame.push(
+            Layer::Hud,
+            Primitive::Text {
+                content: String::from("W"),
+                position: Position::new(50.0, 90.0),
+                font_size: 32,
+                anchor: Anchor::Left,
+                color: [1.0, 1.0, 0.0, 1.0],
+            },
+        );
+
+        // Act
+        let pixmap = Rasterizer::default().draw(&frame).unwrap();
+
+        // Assert
+        let inside = pixmap.pixel(20, 20).unwrap();
+        assert_eq!((inside.red(), inside.green()), (255, 0));
+        let text_pixels = (50..100)
+            .flat_map(|x| (50..100).map(move |y| (x, y)))
+            .filter(|(x, y)| pixmap.pixel(*x, *y).unwrap().green() > 0)
+            .count();
+        assert!(text_pixels > 0);
+    }
+
+    #[test]
+    fn draw_should_fill_rects_and_circles_and_stroke_outlines() {
+        // Arrange
+        let mut frame = RenderFrame::new((100.0, 100.0));
+        frame.push(
+            Layer::Background,
+            Primitive::Rect {
+                rect: [0.0, 0.0, 20.0, 20.0],
+                color: [1.0, 0.0, 0.0, 1.0],
+            },
+        );
+        frame.push(
+            Layer::Heroes,
+            Primitive::Circle {
+                center: Position::new(70.0, 70.0),
+                radius: 10.0,
+                color: [0.0, 1.0, 0.0, 1.0],
+            },
+        );
+        frame.push(
+            Layer::Hud,
+            Primitive::StrokePolygon {
+                points: vec![[30.0, 30.0], [50.0, 30.0], [50.0, 50.0], [30.0, 50.0]],
+                color: [0.0, 0.0, 1.0, 1.0],
+                width: 2.0,
+            },
+        );
+
+        // Act
+        let pixmap = Rasterizer::default().draw(&frame).unwrap();
+
+        // Assert
+        assert_eq!(pixmap.pixel(10, 10).unwrap().red(), 255);
+        assert_eq!(pixmap.pixel(70, 70).unwrap().green(), 255);
+        assert_eq!(pixmap.pixel(40, 30).unwrap().blue(), 255);
+        assert_eq!(pixmap.pixel(40, 40).unwrap().blue(), 0);
+    }
+
+    #[test]
+    fn start_screen_should_match_the_golden_image() {
+        // Arrange
+        let game = seeded_game(1);
+
+        // Act
+        let pixmap = Rasterizer::default().draw(&game.render_frame()).unwrap();
+
+        // Assert
+        assert_matches_golden(&pixmap, "start_screen.png");
+    }
+
+    #[test]
+    fn game_in_progress_should_match_the_golden_image() {
+        // Arrange
+        let mut game = seeded_game(7);
+        game.start(GameMode::Solo);
+        for _ in 0..200 {
+            Autopilot::new().act(&mut game, Game::PLAYER_ONE);
+            game.next_turn();
+        }
+
+        // Act
+        let pixmap = Rasterizer::default().draw(&game.render_frame()).unwrap();
+
+        // Assert
+        assert_matches_golden(&pixmap, "in_game.png");
+    }
+}
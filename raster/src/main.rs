@@ -0,0 +1,146 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use clap::Parser;
+use spacewars_game::{Autopilot, Game, GameMode, MemoryStorage, Replay};
+use spacewars_raster::{Rasterizer, Recorder};
+
+/// Plays a seeded game with the autopilot, or a replay file, and saves its
+/// frames as PNG images or an animated GIF.
+#[derive(Parser, Debug)]
+#[command(name = "spacewars-snapshot")]
+struct Options {
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Number of ticks to play before the last snapshot. Replays play all
+    /// their ticks.
+    #[arg(long, default_value_t = 200)]
+    ticks: u64,
+
+    /// Also save a snapshot every this many ticks.
+    #[arg(long)]
+    every: Option<u64>,
+
+    /// Game mode: solo, coop or versus.
+    #[arg(long, default_value = "solo", value_parser = parse_mode)]
+    mode: GameMode,
+
+    /// Stay on the title screen instead of starting a game.
+    #[arg(long)]
+    title: bool,
+
+    /// Size of the images relative to the 800x600 game screen.
+    #[arg(long, default_value_t = 1.0, value_parser = parse_scale)]
+    scale: f32,
+
+    /// Directory the images are written to.
+    #[arg(long, default_value = "snapshots")]
+    output: PathBuf,
+
+    /// Replay file to play instead of the autopilot, as saved by the
+    /// desktop build.
+    #[arg(long)]
+    replay: Option<PathBuf>,
+
+    /// Also save every tick as an animated GIF in the output directory.
+    #[arg(long)]
+    gif: Option<String>,
+}
+
+fn parse_mode(name: &str) -> Result<GameMode, String> {
+    GameMode::from_name(name).ok_or_else(|| format!("unknown game mode '{}'", name))
+}
+
+fn parse_scale(text: &str) -> Result<f32, String> {
+    let scale: f32 = text.parse().map_err(|error| format!("{}", error))?;
+    if scale.is_finite() && scale > 0.0 {
+        Ok(scale)
+    } else {
+        Err(String::from("must be a positive number"))
+    }
+}
+
+fn load_replay(path: &Path) -> Replay {
+    let replay = fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|text| Replay::from_ron(&text).map_err(|error| error.to_string()));
+
+    replay.unwrap_or_else(|error| {
+        eprintln!("Could not load replay {:?}: {}", path, error);
+        process::exit(1);
+    })
+}
+
+fn main() {
+    let options = Options::parse();
+    if let Err(error) = fs::create_dir_all(&options.output) {
+        eprintln!("Could not create {:?}: {}", options.output, error);
+        process::exit(1);
+    }
+
+    let rasterizer = Rasterizer::new(options.scale);
+    let mut recorder = options.gif.as_ref().map(|_| Recorder::new(options.scale));
+    let replay = options.replay.as_deref().map(load_replay);
+    let last_tick = replay.as_ref().map_or(options.ticks, |replay| replay.ticks);
+
+    let mut on_turn = |tick: u64, game: &Game| {
+        let frame = game.render_frame();
+        let due = options
+            .every
+            .is_some_and(|every| tick.is_multiple_of(every));
+        if due || tick == last_tick {
+            let path = options.output.join(format!("frame-{:05}.png", tick));
+            let saved = rasterizer
+                .draw(&frame)
+                .map_err(|error| error.to_string())
+                .and_then(|pixmap| pixmap.save_png(&path).map_err(|error| error.to_string()));
+            if let Err(error) = saved {
+                eprintln!("Could not save {:?}: {}", path, error);
+                process::exit(1);
+            }
+        }
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.capture(frame);
+        }
+    };
+
+    match &replay {
+        Some(replay) => {
+            if let Err(error) = replay.play(&mut on_turn) {
+                eprintln!("Could not play replay: {}", error);
+                process::exit(1);
+            }
+        }
+        None => {
+            let mut game = Game::new(
+                Game::LOGICAL_WIDTH,
+                Game::LOGICAL_HEIGHT,
+                Box::new(MemoryStorage::default()),
+            )
+            .expect("The logical screen size is valid");
+            game.seed_random(options.seed);
+            if !options.title {
+                game.start(options.mode);
+            }
+
+            let autopilot = Autopilot::new();
+            for tick in 1..=options.ticks {
+                for player in 0..options.mode.player_count() {
+                    autopilot.act(&mut game, player);
+                }
+                game.next_turn();
+                on_turn(tick, &game);
+            }
+        }
+    }
+
+    if let (Some(recorder), Some(name)) = (recorder, &options.gif) {
+        let path = options.output.join(name);
+        if let Err(error) = recorder.save_gif(&path) {
+            eprintln!("Could not save {:?}: {}", path, error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use gif::{Encoder, Frame, Repeat};
+use spacewars_game::{Game, RenderFrame};
+
+use crate::{Pixmap, Rasterizer};
+
+#[derive(Debug)]
+pub enum RecordError {
+    Io(io::Error),
+    Encode(String),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecordError::Io(error) => write!(f, "{}", error),
+            RecordError::Encode(reason) => write!(f, "could not encode frame: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+impl From<io::Error> for RecordError {
+    fn from(error: io::Error) -> RecordError {
+        RecordError::Io(error)
+    }
+}
+
+/// Collects the frames of a game, one per turn, and saves them as an
+/// animated GIF. Frames are only rasterized when
+/// saved, so recording is cheap enough to run alongside a live game.
+pub struct Recorder {
+    rasterizer: Rasterizer,
+    frames: Vec<RenderFrame>,
+}
+
+impl Recorder {
+    /// GIF delays are in hundredths of a second, so the 40 turns per second
+    /// of the game are halved to get an exact delay.
+    pub const GIF_FRAMES_PER_SECOND: u32 = 20;
+    /// Quantization speed of the GIF encoder, from 1 (best) to 30 (fastest).
+    pub const GIF_SPEED: i32 = 10;
+
+    pub fn new(scale: f32) -> Recorder {
+        Recorder {
+            rasterizer: Rasterizer::new(scale),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn capture(&mut self, frame: RenderFrame) {
+        self.frames.push(frame);
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn save_gif(&self, path: &Path) -> Result<(), RecordError> {
+        let step = (Game::TICKS_PER_SECOND / Recorder::GIF_FRAMES_PER_SECOND).max(1) as usize;
+        let delay = (100 / Recorder::GIF_FRAMES_PER_SECOND) as u16;
+        let mut encoder: Option<Encoder<BufWriter<File>>> = None;
+
+        for frame in self.frames.iter().step_by(step) {
+            let pixmap = self.rasterizer.draw(frame).map_err(encode_error)?;
+            let (width, height) = gif_size(&pixmap)?;
+
+            let encoder = match encoder.as_mut() {
+                Some(encoder) => encoder,
+                None => {
+                    let file = BufWriter::new(File::create(path)?);
+                    let mut created =
+                        Encoder::new(file, width, height, &[]).map_err(encode_error)?;
+                    created.set_repeat(Repeat::Infinite).map_err(encode_error)?;
+                    encoder.insert(created)
+                }
+            };
+
+            let mut rgba = straight_rgba(&pixmap);
+            let mut gif_frame =
+                Frame::from_rgba_speed(width, height, &mut rgba, Recorder::GIF_SPEED);
+            gif_frame.delay = delay;
+            encoder.write_frame(&gif_frame).map_err(encode_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn encode_error(error: impl fmt::Display) -> RecordError {
+    RecordError::Encode(error.to_string())
+}
+
+fn gif_size(pixmap: &Pixmap) -> Result<(u16, u16), RecordError> {
+    let width = u16::try_from(pixmap.width()).map_err(encode_error)?;
+    let height = u16::try_from(pixmap.height()).map_err(encode_error)?;
+    Ok((width, height))
+}
+
+fn straight_rgba(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn output_path(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join("spacewars_recorder_tests");
+        fs::create_dir_all(&directory).unwrap();
+        directory.join(name)
+    }
+
+    fn recorder_with_frames(count: usize) -> Recorder {
+        let mut recorder = Recorder::new(0.25);
+        for _ in 0..count {
+            recorder.capture(RenderFrame::new((80.0, 40.0)));
+        }
+        recorder
+    }
+
+    #[test]
+    fn save_gif_should_keep_one_frame_per_gif_delay() {
+        // Arrange
+        let recorder = recorder_with_frames(10);
+        let path = output_path("animation.gif");
+
+        // Act
+        recorder.save_gif(&path).unwrap();
+
+        // Assert
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&path).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (20, 10));
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 5);
+            frames += 1;
+        }
+        assert_eq!(frames, 5);
+    }
+}
@@ -0,0 +1,181 @@
+use std::thread;
+
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use spacewars_game::{Game, GameMode, MemoryStorage};
+
+use crate::policy::PolicyKind;
+use crate::report::{GameReport, Summary};
+
+mod policy;
+mod report;
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueEnum)]
+enum Format {
+    Json,
+    Csv,
+}
+
+/// Runs Spacewars games without a window and prints their statistics.
+#[derive(Parser, Debug)]
+#[command(name = "spacewars-sim")]
+struct Options {
+    /// Seed of the first game; game `n` uses `seed + n`.
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Maximum number of ticks per game.
+    #[arg(long, default_value_t = 5 * 60 * Game::TICKS_PER_SECOND as u64)]
+    ticks: u64,
+
+    /// Number of games to run.
+    #[arg(long, default_value_t = 1)]
+    games: u64,
+
+    /// Number of worker threads; defaults to the available parallelism.
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Bot policy driving every player.
+    #[arg(long, value_enum, default_value_t = PolicyKind::Autopilot)]
+    policy: PolicyKind,
+
+    /// Game mode: solo, coop or versus.
+    #[arg(long, default_value = "solo", value_parser = parse_mode)]
+    mode: GameMode,
+
+    #[arg(long, value_enum, default_value_t = Format::Json)]
+    format: Format,
+}
+
+#[derive(Serialize)]
+struct Output<'a> {
+    summary: Summary,
+    games: &'a [GameReport],
+}
+
+fn parse_mode(name: &str) -> Result<GameMode, String> {
+    GameMode::from_name(name).ok_or_else(|| format!("unknown game mode '{}'", name))
+}
+
+fn simulate(seed: u64, options: &Options) -> GameReport {
+    let mut game = Game::new(
+        Game::LOGICAL_WIDTH,
+        Game::LOGICAL_HEIGHT,
+        Box::new(MemoryStorage::default()),
+    )
+    .expect("The logical screen size is valid");
+    game.seed_random(seed);
+    game.start(options.mode);
+
+    let mut policies: Vec<_> = (0..options.mode.player_count())
+        .map(|player| options.policy.build(seed.wrapping_add(player as u64)))
+        .collect();
+
+    let mut ticks = 0;
+    while ticks < options.ticks && !game.is_over() {
+        for (player, policy) in policies.iter_mut().enumerate() {
+            policy.act(&mut game, player);
+        }
+        game.next_turn();
+        ticks += 1;
+    }
+
+    GameReport::new(seed, ticks, &game)
+}
+
+fn run(options: &Options) -> Vec<GameReport> {
+    let threads = options
+        .threads
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1)
+        .max(1) as u64;
+
+    let mut reports: Vec<GameReport> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|worker| {
+                scope.spawn(move || {
+                    (worker..options.games)
+                        .step_by(threads as usize)
+                        .map(|game| simulate(options.seed.wrapping_add(game), options))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("Simulation thread panicked"))
+            .collect()
+    });
+
+    reports.sort_by_key(|report| report.seed.wrapping_sub(options.seed));
+    reports
+}
+
+fn main() {
+    let options = Options::parse();
+    let reports = run(&options);
+
+    match options.format {
+        Format::Json => {
+            let output = Output {
+                summary: Summary::new(&reports),
+                games: &reports,
+            };
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output).expect("Failed to serialize statistics")
+            );
+        }
+        Format::Csv => {
+            println!("{}", GameReport::CSV_HEADER);
+            for report in &reports {
+                println!("{}", report.csv_row());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(policy: PolicyKind) -> Options {
+        Options::parse_from([
+            "spacewars-sim",
+            "--ticks",
+            "2000",
+            "--policy",
+            policy.to_possible_value().unwrap().get_name(),
+        ])
+    }
+
+    #[test]
+    fn simulate_should_be_repeatable_for_the_same_seed() {
+        // Arrange
+        let options = options(PolicyKind::Random);
+
+        // Act
+        let first = simulate(7, &options);
+        let second = simulate(7, &options);
+
+        // Assert
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn run_should_report_every_game_in_seed_order() {
+        // Arrange
+        let mut options = options(PolicyKind::Idle);
+        options.games = 5;
+        options.threads = Some(2);
+
+        // Act
+        let reports = run(&options);
+
+        // Assert
+        let seeds: Vec<u64> = reports.iter().map(|report| report.seed).collect();
+        assert_eq!(seeds, vec![1, 2, 3, 4, 5]);
+    }
+}
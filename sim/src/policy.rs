@@ -0,0 +1,72 @@
+use clap::ValueEnum;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use spacewars_game::{Autopilot, Direction, Game, PlayerId};
+
+pub trait Policy {
+    fn act(&mut self, game: &mut Game, player: PlayerId);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, ValueEnum)]
+pub enum PolicyKind {
+    Idle,
+    Random,
+    Autopilot,
+}
+
+impl PolicyKind {
+    pub fn build(&self, seed: u64) -> Box<dyn Policy> {
+        match *self {
+            PolicyKind::Idle => Box::new(Idle),
+            PolicyKind::Random => Box::new(RandomPolicy::new(seed)),
+            PolicyKind::Autopilot => Box::new(Autopilot::new()),
+        }
+    }
+}
+
+pub struct Idle;
+
+impl Policy for Idle {
+    fn act(&mut self, _game: &mut Game, _player: PlayerId) {}
+}
+
+pub struct RandomPolicy {
+    random: StdRng,
+}
+
+impl RandomPolicy {
+    pub const FIRE_RATE: f64 = 0.3;
+    pub const DASH_RATE: f64 = 0.01;
+    pub const DIRECTIONS: [Direction; 4] = [
+        Direction::LEFT,
+        Direction::UP,
+        Direction::RIGHT,
+        Direction::DOWN,
+    ];
+
+    pub fn new(seed: u64) -> RandomPolicy {
+        RandomPolicy {
+            random: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Policy for RandomPolicy {
+    fn act(&mut self, game: &mut Game, player: PlayerId) {
+        let index = self.random.gen_range(0..RandomPolicy::DIRECTIONS.len());
+        game.move_player(player, RandomPolicy::DIRECTIONS[index]);
+
+        if self.random.gen_bool(RandomPolicy::FIRE_RATE) {
+            game.fire_player_attack(player);
+        }
+        if self.random.gen_bool(RandomPolicy::DASH_RATE) {
+            game.dash_player(player);
+        }
+    }
+}
+
+impl Policy for Autopilot {
+    fn act(&mut self, game: &mut Game, player: PlayerId) {
+        Autopilot::act(self, game, player);
+    }
+}
@@ -0,0 +1,165 @@
+use serde::Serialize;
+use spacewars_game::{Game, GameScore};
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GameReport {
+    pub seed: u64,
+    pub ticks: u64,
+    pub game_over: bool,
+    pub survival_seconds: f64,
+    pub score: GameScore,
+    pub kills: u32,
+    pub shots_fired: u32,
+    pub accuracy: u32,
+    pub kills_per_minute: f64,
+}
+
+impl GameReport {
+    pub const CSV_HEADER: &'static str =
+        "seed,ticks,game_over,survival_seconds,score,kills,shots_fired,accuracy,kills_per_minute";
+
+    pub fn new(seed: u64, ticks: u64, game: &Game) -> GameReport {
+        let stats: Vec<_> = (0..game.mode().player_count())
+            .filter_map(|player| game.player_stats(player))
+            .collect();
+
+        let score = stats.iter().map(|stats| stats.score).sum();
+        let kills: u32 = stats.iter().map(|stats| stats.kills).sum();
+        let shots_fired: u32 = stats.iter().map(|stats| stats.shots_fired).sum();
+        let accuracy = (kills.min(shots_fired) * 100)
+            .checked_div(shots_fired)
+            .unwrap_or(0);
+
+        let survival_seconds = ticks as f64 / f64::from(Game::TICKS_PER_SECOND);
+        let kills_per_minute = if ticks == 0 {
+            0.0
+        } else {
+            f64::from(kills) * 60.0 / survival_seconds
+        };
+
+        GameReport {
+            seed,
+            ticks,
+            game_over: game.is_over(),
+            survival_seconds,
+            score,
+            kills,
+            shots_fired,
+            accuracy,
+            kills_per_minute,
+        }
+    }
+
+    pub fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{:.2},{},{},{},{},{:.2}",
+            self.seed,
+            self.ticks,
+            self.game_over,
+            self.survival_seconds,
+            self.score,
+            self.kills,
+            self.shots_fired,
+            self.accuracy,
+            self.kills_per_minute
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Summary {
+    pub games: usize,
+    pub game_overs: usize,
+    pub min_score: GameScore,
+    pub median_score: GameScore,
+    pub p90_score: GameScore,
+    pub max_score: GameScore,
+    pub mean_score: f64,
+    pub mean_survival_seconds: f64,
+    pub mean_kills_per_minute: f64,
+    pub total_shots_fired: u64,
+}
+
+impl Summary {
+    pub fn new(reports: &[GameReport]) -> Summary {
+        if reports.is_empty() {
+            return Summary::default();
+        }
+
+        let games = reports.len();
+        let mut scores: Vec<GameScore> = reports.iter().map(|report| report.score).collect();
+        scores.sort_unstable();
+
+        let mean = |value: fn(&GameReport) -> f64| -> f64 {
+            reports.iter().map(value).sum::<f64>() / games as f64
+        };
+
+        Summary {
+            games,
+            game_overs: reports.iter().filter(|report| report.game_over).count(),
+            min_score: scores[0],
+            median_score: percentile(&scores, 50),
+            p90_score: percentile(&scores, 90),
+            max_score: scores[games - 1],
+            mean_score: mean(|report| f64::from(report.score)),
+            mean_survival_seconds: mean(|report| report.survival_seconds),
+            mean_kills_per_minute: mean(|report| report.kills_per_minute),
+            total_shots_fired: reports
+                .iter()
+                .map(|report| u64::from(report.shots_fired))
+                .sum(),
+        }
+    }
+}
+
+fn percentile(sorted: &[GameScore], percent: usize) -> GameScore {
+    let index = (sorted.len() * percent).div_ceil(100).max(1) - 1;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(score: GameScore, ticks: u64) -> GameReport {
+        GameReport {
+            seed: 0,
+            ticks,
+            game_over: true,
+            survival_seconds: ticks as f64 / f64::from(Game::TICKS_PER_SECOND),
+            score,
+            kills: 0,
+            shots_fired: 2,
+            accuracy: 0,
+            kills_per_minute: 0.0,
+        }
+    }
+
+    #[test]
+    fn summary_should_describe_the_score_distribution() {
+        // Arrange
+        let reports: Vec<GameReport> = (1..=10).map(|i| report(i * 10, 40)).collect();
+
+        // Act
+        let summary = Summary::new(&reports);
+
+        // Assert
+        assert_eq!(summary.games, 10);
+        assert_eq!(summary.min_score, 10);
+        assert_eq!(summary.median_score, 50);
+        assert_eq!(summary.p90_score, 90);
+        assert_eq!(summary.max_score, 100);
+        assert_eq!(summary.mean_score, 55.0);
+        assert_eq!(summary.mean_survival_seconds, 1.0);
+        assert_eq!(summary.total_shots_fired, 20);
+    }
+
+    #[test]
+    fn summary_should_be_empty_without_reports() {
+        // Act
+        let summary = Summary::new(&[]);
+
+        // Assert
+        assert_eq!(summary, Summary::default());
+    }
+}
@@ -0,0 +1,312 @@
+use std::io::{self, Write};
+
+use crossterm::style::{self, Print, ResetColor, SetForegroundColor};
+use crossterm::{cursor, queue};
+use spacewars_game::{Anchor, Color, Position, Primitive, RenderFrame, Vector, Viewport};
+
+/// Braille patterns split each character cell into 2x4 dots.
+const DOTS_PER_COLUMN: usize = 2;
+const DOTS_PER_ROW: usize = 4;
+const BRAILLE_BLANK: u32 = 0x2800;
+const BRAILLE_BITS: [[u8; DOTS_PER_ROW]; DOTS_PER_COLUMN] =
+    [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct Cell {
+    dots: u8,
+    color: Option<Color>,
+    text: Option<(char, Color)>,
+}
+
+/// A frame rasterized to terminal cells: shapes as braille dots and texts as
+/// characters on top of them.
+pub struct Canvas {
+    columns: usize,
+    rows: usize,
+    cells: Vec<Cell>,
+    viewport: Viewport,
+}
+
+impl Canvas {
+    pub fn draw(frame: &RenderFrame, columns: u16, rows: u16) -> Canvas {
+        let (columns, rows) = (usize::from(columns), usize::from(rows));
+        let dots = (
+            (columns * DOTS_PER_COLUMN) as f64,
+            (rows * DOTS_PER_ROW) as f64,
+        );
+
+        let mut canvas = Canvas {
+            columns,
+            rows,
+            cells: vec![Cell::default(); columns * rows],
+            viewport: Viewport::fit(frame.size(), dots),
+        };
+        for command in frame.commands() {
+            canvas.draw_primitive(&command.primitive);
+        }
+        canvas
+    }
+
+    pub fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut current = None;
+
+        for row in 0..self.rows {
+            queue!(out, cursor::MoveTo(0, row as u16))?;
+
+            for cell in &self.cells[row * self.columns..(row + 1) * self.columns] {
+                let (character, color) = match (cell.text, cell.color) {
+                    (Some((character, color)), _) => (character, Some(color)),
+                    (None, Some(color)) if cell.dots != 0 => (braille(cell.dots), Some(color)),
+                    _ => (' ', None),
+                };
+
+                if let Some(color) = color.filter(|color| Some(*color) != current) {
+                    queue!(out, SetForegroundColor(terminal_color(color)))?;
+                    current = Some(color);
+                }
+                queue!(out, Print(character))?;
+            }
+        }
+
+        queue!(out, ResetColor)
+    }
+
+    fn draw_primitive(&mut self, primitive: &Primitive) {
+        match primitive {
+            Primitive::FillPolygon { points, color } => {
+                let points: Vec<Position> = points
+                    .iter()
+                    .map(|[x, y]| self.viewport.to_physical(Vector::new(*x, *y)))
+                    .collect();
+                self.fill_polygon(&points, *color);
+            }
+            Primitive::StrokePolygon { points, color, .. } => {
+                for (i, [x, y]) in points.iter().enumerate() {
+                    let [next_x, next_y] = points[(i + 1) % points.len()];
+                    let from = self.viewport.to_physical(Vector::new(*x, *y));
+                    let to = self.viewport.to_physical(Vector::new(next_x, next_y));
+                    self.line(from, to, *color);
+                }
+            }
+            Primitive::Rect { rect, color } => {
+                let [x, y, width, height] = *rect;
+                let corners = [
+                    [x, y],
+                    [x + width, y],
+                    [x + width, y + height],
+                    [x, y + height],
+                ];
+                let points: Vec<Position> = corners
+                    .iter()
+                    .map(|[x, y]| self.viewport.to_physical(Vector::new(*x, *y)))
+                    .collect();
+                self.fill_polygon(&points, *color);
+            }
+            Primitive::Circle {
+                center,
+                radius,
+                color,
+            } => {
+                let center = self.viewport.to_physical(*center);
+                let radius = radius * self.viewport.scale();
+                let points: Vec<Position> = (0..16)
+                    .map(|i| {
+                        center
+                            + Vector::from_angle(f64::from(i) * std::f64::consts::TAU / 16.0)
+                                * radius
+                    })
+                    .collect();
+                self.fill_polygon(&points, *color);
+            }
+            Primitive::Text {
+                content,
+                position,
+                anchor,
+                color,
+                ..
+            } => self.put_text(content, *position, *anchor, *color),
+        }
+    }
+
+    /// Fills the dots whose centers are inside the polygon, or the dot under
+    /// its center when the polygon is smaller than a dot.
+    fn fill_polygon(&mut self, points: &[Position], color: Color) {
+        if points.is_empty() {
+            return;
+        }
+
+        let top = points.iter().map(|point| point.y).fold(f64::MAX, f64::min);
+        let bottom = points.iter().map(|point| point.y).fold(f64::MIN, f64::max);
+
+        let mut filled = false;
+        for y in top.floor().max(0.0) as i64..=bottom.ceil() as i64 {
+            let center_y = y as f64 + 0.5;
+            let mut crossings: Vec<f64> = points
+                .iter()
+                .zip(points.iter().cycle().skip(1))
+                .filter(|(a, b)| (a.y <= center_y) != (b.y <= center_y))
+                .map(|(a, b)| a.x + (center_y - a.y) / (b.y - a.y) * (b.x - a.x))
+                .collect();
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+            for span in crossings.chunks(2) {
+                if let [left, right] = *span {
+                    for x in (left - 0.5).ceil() as i64..=(right - 0.5).floor() as i64 {
+                        filled |= self.set_dot(x, y, color);
+                    }
+                }
+            }
+        }
+
+        if !filled {
+            let sum = points.iter().fold(Vector::ZERO, |sum, point| sum + *point);
+            let center = sum * (1.0 / points.len() as f64);
+            self.set_dot(center.x as i64, center.y as i64, color);
+        }
+    }
+
+    fn line(&mut self, from: Position, to: Position, color: Color) {
+        let steps = (to - from).length().ceil().max(1.0) as i64;
+        for step in 0..=steps {
+            let point = from + (to - from) * (step as f64 / steps as f64);
+            self.set_dot(point.x as i64, point.y as i64, color);
+        }
+    }
+
+    fn set_dot(&mut self, x: i64, y: i64, color: Color) -> bool {
+        if x < 0 || y < 0 {
+            return false;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let (column, row) = (x / DOTS_PER_COLUMN, y / DOTS_PER_ROW);
+        if column >= self.columns || row >= self.rows {
+            return false;
+        }
+
+        let cell = &mut self.cells[row * self.columns + column];
+        cell.dots |= BRAILLE_BITS[x % DOTS_PER_COLUMN][y % DOTS_PER_ROW];
+        cell.color = Some(color);
+        true
+    }
+
+    /// Texts are placed on the cell row just above their baseline.
+    fn put_text(&mut self, content: &str, position: Position, anchor: Anchor, color: Color) {
+        let physical = self.viewport.to_physical(position);
+        let length = content.chars().count() as i64;
+        let column = (physical.x / DOTS_PER_COLUMN as f64) as i64;
+        let start = match anchor {
+            Anchor::Left => column,
+            Anchor::Center => column - length / 2,
+            Anchor::Right => column - length,
+        };
+        let row = (physical.y / DOTS_PER_ROW as f64) as i64 - 1;
+        if row < 0 || row as usize >= self.rows {
+            return;
+        }
+
+        for (i, character) in content.chars().enumerate() {
+            let column = start + i as i64;
+            if column < 0 || column as usize >= self.columns {
+                continue;
+            }
+            self.cells[row as usize * self.columns + column as usize].text =
+                Some((character, color));
+        }
+    }
+}
+
+fn braille(dots: u8) -> char {
+    char::from_u32(BRAILLE_BLANK + u32::from(dots)).unwrap_or(' ')
+}
+
+fn terminal_color(color: Color) -> style::Color {
+    let [red, green, blue, alpha] = color;
+    let channel = |value: f32| (value * alpha).clamp(0.0, 1.0) * 255.0;
+    style::Color::Rgb {
+        r: channel(red) as u8,
+        g: channel(green) as u8,
+        b: channel(blue) as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spacewars_game::Layer;
+
+    const RED: Color = [1.0, 0.0, 0.0, 1.0];
+
+    fn frame_with(primitive: Primitive) -> RenderFrame {
+        let mut frame = RenderFrame::new((80.0, 40.0));
+        frame.push(Layer::Heroes, primitive);
+        frame
+    }
+
+    #[test]
+    fn draw_should_fill_braille_dots_inside_polygons() {
+        // Arrange
+        let frame = frame_with(Primitive::FillPolygon {
+            points: vec![[0.0, 0.0], [2.0, 0.0], [2.0, 4.0], [0.0, 4.0]],
+            color: RED,
+        });
+
+        // Act
+        let canvas = Canvas::draw(&frame, 40, 10);
+
+        // Assert
+        assert_eq!(canvas.cells[0].dots, 0xFF);
+        assert_eq!(canvas.cells[0].color, Some(RED));
+        assert_eq!(canvas.cells[1].dots, 0);
+    }
+
+    #[test]
+    fn draw_should_only_set_the_dots_along_stroked_polygons() {
+        // Arrange
+        let frame = frame_with(Primitive::StrokePolygon {
+            points: vec![[0.0, 0.0], [20.0, 0.0], [20.0, 16.0], [0.0, 16.0]],
+            color: RED,
+            width: 1.0,
+        });
+
+        // Act
+        let canvas = Canvas::draw(&frame, 40, 10);
+
+        // Assert
+        assert_ne!(canvas.cells[0].dots, 0);
+        assert_eq!(canvas.cells[40 + 2].dots, 0);
+    }
+
+    #[test]
+    fn draw_should_keep_tiny_shapes_visible() {
+        // Arrange
+        let frame = frame_with(Primitive::FillPolygon {
+            points: vec![[40.0, 20.0], [40.2, 20.0], [40.2, 20.2]],
+            color: RED,
+        });
+
+        // Act
+        let canvas = Canvas::draw(&frame, 40, 10);
+
+        // Assert
+        assert_eq!(canvas.cells.iter().filter(|cell| cell.dots != 0).count(), 1);
+    }
+
+    #[test]
+    fn draw_should_write_texts_above_their_baseline() {
+        // Arrange
+        let frame = frame_with(Primitive::Text {
+            content: String::from("Hi"),
+            position: Vector::new(4.0, 8.0),
+            font_size: 32,
+            anchor: Anchor::Left,
+            color: RED,
+        });
+
+        // Act
+        let canvas = Canvas::draw(&frame, 40, 10);
+
+        // Assert
+        assert_eq!(canvas.cells[40 + 2].text, Some(('H', RED)));
+        assert_eq!(canvas.cells[40 + 3].text, Some(('i', RED)));
+    }
+}
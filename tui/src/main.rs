@@ -0,0 +1,85 @@
+use std::io::{self, Write};
+use std::process;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event};
+use crossterm::{cursor, execute, terminal};
+
+use crate::canvas::Canvas;
+use crate::presenter::Presenter;
+use spacewars_files::{high_score_path, load_bindings, load_campaign, load_config, FileStorage};
+use spacewars_game::{Game, GameMode};
+
+mod canvas;
+mod presenter;
+
+const FRAMES_PER_SECOND: u64 = 40;
+
+/// Switches the terminal to raw mode on the alternate screen and restores it
+/// when dropped, also when the game panics.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            terminal::EnterAlternateScreen,
+            cursor::Hide,
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn run(presenter: &mut Presenter) -> io::Result<()> {
+    let frame_time = Duration::from_millis(1000 / FRAMES_PER_SECOND);
+    let mut stdout = io::stdout();
+
+    while !presenter.should_quit() {
+        let started = Instant::now();
+
+        let (columns, rows) = terminal::size()?;
+        Canvas::draw(&presenter.next_frame(), columns, rows).write_to(&mut stdout)?;
+        stdout.flush()?;
+
+        while let Some(remaining) = frame_time.checked_sub(started.elapsed()) {
+            if !event::poll(remaining)? {
+                break;
+            }
+            if let Event::Key(key) = event::read()? {
+                presenter.key_event(key);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let storage = FileStorage::new(high_score_path());
+    let mut game = match Game::with_config(load_config(), Box::new(storage)) {
+        Ok(game) => game,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+    if let Some(campaign) = load_campaign() {
+        for mode in [GameMode::Solo, GameMode::Coop] {
+            game.set_campaign(mode, campaign.clone())
+                .expect("Campaign is validated when loaded");
+        }
+    }
+    let mut presenter = Presenter::new(game, load_bindings());
+
+    let _guard = TerminalGuard::enter()?;
+    run(&mut presenter)
+}
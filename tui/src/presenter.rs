@@ -0,0 +1,93 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use spacewars_files::save_bindings;
+use spacewars_game::{Game, KeyBindings, Rebinding, RenderFrame};
+
+const REBIND_KEY: KeyCode = KeyCode::F(2);
+/// Leaves the rebinding screen, or the game.
+const QUIT_KEY: KeyCode = KeyCode::Esc;
+
+pub struct Presenter {
+    game: Game,
+    bindings: KeyBindings,
+    rebinding: Option<Rebinding>,
+    quit: bool,
+}
+
+impl Presenter {
+    pub fn new(game: Game, bindings: KeyBindings) -> Presenter {
+        Presenter {
+            game,
+            bindings,
+            rebinding: None,
+            quit: false,
+        }
+    }
+
+    pub fn next_frame(&mut self) -> RenderFrame {
+        match &self.rebinding {
+            Some(rebinding) => rebinding.render_frame(self.game.screen_size()),
+            None => {
+                self.game.next_turn();
+                self.game.render_frame()
+            }
+        }
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Terminals only report key presses and repeats, so every press or
+    /// repeat moves the hero one step.
+    pub fn key_event(&mut self, event: KeyEvent) {
+        if event.kind == KeyEventKind::Release {
+            return;
+        }
+
+        let ctrl_c =
+            event.code == KeyCode::Char('c') && event.modifiers.contains(KeyModifiers::CONTROL);
+        if ctrl_c {
+            self.quit = true;
+            return;
+        }
+
+        if let Some(rebinding) = self.rebinding.as_mut() {
+            if event.code == QUIT_KEY {
+                self.rebinding = None;
+                return;
+            }
+            rebinding.press(&key_name(event.code));
+            if rebinding.is_cancelled() {
+                self.rebinding = None;
+            } else if rebinding.is_done() {
+                self.bindings = rebinding.bindings().clone();
+                self.rebinding = None;
+                // The terminal is in use by the game, so errors are ignored.
+                let _ = save_bindings(&self.bindings);
+            }
+            return;
+        }
+
+        match event.code {
+            QUIT_KEY => self.quit = true,
+            REBIND_KEY => self.rebinding = Some(Rebinding::new(self.bindings.clone())),
+            code => {
+                let player_count = self.game.mode().player_count();
+                for input in self.bindings.inputs(&key_name(code), player_count) {
+                    input.apply(&mut self.game);
+                }
+            }
+        }
+    }
+}
+
+/// Names terminal keys as the shared key bindings do.
+fn key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(character) => KeyBindings::character_key(character),
+        KeyCode::Esc => String::from("Escape"),
+        KeyCode::F(number) => format!("F{}", number),
+        code => format!("{:?}", code),
+    }
+}
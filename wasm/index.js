@@ -1,2 +1,7 @@
 import('./pkg')
-  .catch(console.error);
\ No newline at end of file
+  .then(({ SpaceWars }) => {
+    const canvas = document.getElementById('canvas');
+    const game = new SpaceWars(canvas, { fitWindow: true, globalKeys: true });
+    game.start();
+  })
+  .catch(console.error);
@@ -0,0 +1,63 @@
+use spacewars_game::{Campaign, ConfigError, GameConfig};
+use wasm_bindgen::JsValue;
+use web_sys::Window;
+
+const CONFIG_PROPERTY: &str = "spacewarsConfig";
+const CAMPAIGN_PROPERTY: &str = "spacewarsCampaign";
+
+pub fn load_config(window: &Window) -> GameConfig {
+    warn_on_error(CONFIG_PROPERTY, read_config(window, CONFIG_PROPERTY)).unwrap_or_default()
+}
+
+pub fn load_campaign(window: &Window) -> Option<Campaign> {
+    warn_on_error(CAMPAIGN_PROPERTY, read_campaign(window, CAMPAIGN_PROPERTY))
+}
+
+/// Reads and validates a `GameConfig` from a property of a JS object,
+/// `Ok(None)` when it is not set.
+pub fn read_config(object: &JsValue, property: &str) -> Result<Option<GameConfig>, ConfigError> {
+    read_property(object, property).and_then(|json| match json {
+        Some(json) => {
+            let config: GameConfig = serde_json::from_str(&json)
+                .map_err(|error| ConfigError::Parse(error.to_string()))?;
+            config.validate()?;
+            Ok(Some(config))
+        }
+        None => Ok(None),
+    })
+}
+
+pub fn read_campaign(object: &JsValue, property: &str) -> Result<Option<Campaign>, ConfigError> {
+    read_property(object, property).and_then(|json| match json {
+        Some(json) => {
+            let campaign: Campaign = serde_json::from_str(&json)
+                .map_err(|error| ConfigError::Parse(error.to_string()))?;
+            campaign.validate()?;
+            Ok(Some(campaign))
+        }
+        None => Ok(None),
+    })
+}
+
+/// Reads a JS object property as JSON, `Ok(None)` when it is not set.
+fn read_property(object: &JsValue, property: &str) -> Result<Option<String>, ConfigError> {
+    let value =
+        js_sys::Reflect::get(object, &JsValue::from_str(property)).unwrap_or(JsValue::UNDEFINED);
+    if value.is_undefined() || value.is_null() {
+        return Ok(None);
+    }
+
+    js_sys::JSON::stringify(&value)
+        .ok()
+        .and_then(|json| json.as_string())
+        .map(Some)
+        .ok_or_else(|| ConfigError::Parse("not a JSON object".to_string()))
+}
+
+fn warn_on_error<T>(property: &str, result: Result<Option<T>, ConfigError>) -> Option<T> {
+    result.unwrap_or_else(|error| {
+        let message = format!("Ignoring window.{}: {}", property, error);
+        web_sys::console::warn_1(&message.into());
+        None
+    })
+}
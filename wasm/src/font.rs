@@ -0,0 +1,58 @@
+use std::cell::Cell;
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{FontFace, Window};
+
+/// The desktop build renders texts with the same font, and SVG screenshots
+/// embed it too.
+const GAME_FONT: &str = "Nova Square";
+const FALLBACK_FONT: &str = "Arial";
+const FONT_DATA: &[u8] = include_bytes!("../../assets/NovaSquare-Regular.ttf");
+
+#[derive(Copy, Clone, PartialEq)]
+enum FontState {
+    Unloaded,
+    Loading,
+    Ready,
+}
+
+thread_local! {
+    static FONT_STATE: Cell<FontState> = const { Cell::new(FontState::Unloaded) };
+}
+
+/// Starts loading the bundled font on the first call, shared by every game
+/// of the page, and tells whether texts can be drawn. A font that fails to
+/// load is reported, and texts fall back to Arial.
+pub fn font_ready(window: &Window) -> bool {
+    match FONT_STATE.with(Cell::get) {
+        FontState::Unloaded => {
+            FONT_STATE.with(|state| state.set(FontState::Loading));
+            let window = window.clone();
+            spawn_local(async move {
+                if let Err(error) = load_font(&window).await {
+                    web_sys::console::warn_2(&"Could not load the game font:".into(), &error);
+                }
+                FONT_STATE.with(|state| state.set(FontState::Ready));
+            });
+            false
+        }
+        FontState::Loading => false,
+        FontState::Ready => true,
+    }
+}
+
+async fn load_font(window: &Window) -> Result<(), JsValue> {
+    let font = FontFace::new_with_u8_array(GAME_FONT, FONT_DATA)?;
+    JsFuture::from(font.load()?).await?;
+
+    let document = window
+        .document()
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    document.fonts().add(&font)
+}
+
+/// The CSS font of a text. Sizes are in points, as on the desktop.
+pub fn css_font(font_size: u32) -> String {
+    format!("{}pt '{}', {}", font_size, GAME_FONT, FALLBACK_FONT)
+}
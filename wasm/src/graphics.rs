@@ -1,12 +1,11 @@
+use crate::font::css_font;
 use colorsys::Rgb;
 use spacewars_game::Color;
-use spacewars_game::{GameText, ScreenSize, SpaceObject};
+use spacewars_game::{Anchor, Primitive, RenderFrame, ScreenSize, Viewport};
 use wasm_bindgen::prelude::*;
 use web_sys::CanvasRenderingContext2d;
 
 const COLOR_MAX_RANGE: f32 = 255.0;
-const GAME_FONT: &str = "Arial";
-const BLACK_COLOR_HEX: &str = "#000000";
 
 fn convert_color(color: Color) -> String {
     let [red, green, blue, alpha] = color;
@@ -16,14 +15,73 @@ fn convert_color(color: Color) -> String {
         blue * COLOR_MAX_RANGE,
         alpha,
     ))
-    .to_hex_string()
+    .to_css_string()
 }
 
-pub fn draw_polygon(object: &Box<&dyn SpaceObject>, context: &CanvasRenderingContext2d) {
-    context.set_fill_style(&JsValue::from(convert_color(object.color())));
-    context.begin_path();
+pub fn draw_frame(frame: &RenderFrame, context: &CanvasRenderingContext2d) {
+    for command in frame.commands() {
+        draw_primitive(&command.primitive, context);
+    }
+}
+
+fn draw_primitive(primitive: &Primitive, context: &CanvasRenderingContext2d) {
+    match primitive {
+        Primitive::FillPolygon { points, color } => {
+            trace_polygon(points, context);
+            context.set_fill_style(&JsValue::from(convert_color(*color)));
+            context.fill();
+        }
+        Primitive::StrokePolygon {
+            points,
+            color,
+            width,
+        } => {
+            trace_polygon(points, context);
+            context.set_stroke_style(&JsValue::from(convert_color(*color)));
+            context.set_line_width(*width);
+            context.stroke();
+        }
+        Primitive::Rect { rect, color } => {
+            let [x, y, width, height] = *rect;
+            context.set_fill_style(&JsValue::from(convert_color(*color)));
+            context.fill_rect(x, y, width, height);
+        }
+        Primitive::Circle {
+            center,
+            radius,
+            color,
+        } => {
+            context.begin_path();
+            context
+                .arc(center.x, center.y, *radius, 0.0, std::f64::consts::TAU)
+                .expect("Error Drawing Circle");
+            context.set_fill_style(&JsValue::from(convert_color(*color)));
+            context.fill();
+        }
+        Primitive::Text {
+            content,
+            position,
+            font_size,
+            anchor,
+            color,
+        } => {
+            context.set_font(&css_font(*font_size));
+            context.set_text_align(match anchor {
+                Anchor::Left => "left",
+                Anchor::Center => "center",
+                Anchor::Right => "right",
+            });
+            context.set_fill_style(&JsValue::from(convert_color(*color)));
+            context
+                .fill_text(content, position.x, position.y)
+                .expect("Error Filling Text");
+        }
+    }
+}
 
-    for (i, [x, y]) in object.coord().iter().enumerate() {
+fn trace_polygon(points: &[[f64; 2]], context: &CanvasRenderingContext2d) {
+    context.begin_path();
+    for (i, [x, y]) in points.iter().enumerate() {
         if i == 0 {
             context.move_to(*x, *y);
         } else {
@@ -31,21 +89,24 @@ pub fn draw_polygon(object: &Box<&dyn SpaceObject>, context: &CanvasRenderingCon
         }
     }
     context.close_path();
-    context.fill();
 }
 
-pub fn draw_text(text: &GameText, context: &CanvasRenderingContext2d) {
-    let (x, y) = text.position();
+pub fn draw_background(context: &CanvasRenderingContext2d, screen_size: ScreenSize, color: Color) {
+    let (width, height) = screen_size;
+    context.set_fill_style(&JsValue::from(convert_color(color)));
+    context.fill_rect(0.0, 0.0, width, height);
+}
 
-    context.set_font(format!("{} {}", text.font_size(), GAME_FONT).as_str());
-    context.set_fill_style(&JsValue::from(convert_color(text.color())));
+pub fn set_viewport_transform(context: &CanvasRenderingContext2d, viewport: &Viewport) {
+    let scale = viewport.scale();
+    let offset = viewport.offset();
     context
-        .fill_text(text.content().as_str(), x, y)
-        .expect("Error Filling Text");
+        .set_transform(scale, 0.0, 0.0, scale, offset.x, offset.y)
+        .expect("Error Setting Transform");
 }
 
-pub fn draw_background(context: &CanvasRenderingContext2d, screen_size: ScreenSize) {
-    let (width, height) = screen_size;
-    context.set_fill_style(&JsValue::from(BLACK_COLOR_HEX));
-    context.fill_rect(0.0, 0.0, width, height);
+pub fn reset_transform(context: &CanvasRenderingContext2d) {
+    context
+        .set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+        .expect("Error Resetting Transform");
 }
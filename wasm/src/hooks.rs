@@ -0,0 +1,78 @@
+use js_sys::Function;
+use spacewars_game::{Game, GameScore};
+use std::cell::Cell;
+use wasm_bindgen::JsValue;
+
+/// What the page callbacks need to know about the game after a turn.
+#[derive(Copy, Clone)]
+pub struct GameStatus {
+    pub score: GameScore,
+    pub live: bool,
+    pub over: bool,
+}
+
+impl GameStatus {
+    /// The score adds up every player, as co-op players share the game.
+    pub fn of(game: &Game) -> GameStatus {
+        let score = (0..game.mode().player_count())
+            .filter_map(|player| game.player_stats(player))
+            .map(|stats| stats.score)
+            .sum();
+
+        GameStatus {
+            score,
+            live: !game.is_demo(),
+            over: game.is_over(),
+        }
+    }
+}
+
+/// Page callbacks, called as a game scores and ends. The attract mode never
+/// calls them.
+#[derive(Default)]
+pub struct Hooks {
+    on_score: Option<Function>,
+    on_game_over: Option<Function>,
+    last_score: Cell<GameScore>,
+    was_over: Cell<bool>,
+}
+
+impl Hooks {
+    pub fn new(on_score: Option<Function>, on_game_over: Option<Function>) -> Hooks {
+        Hooks {
+            on_score,
+            on_game_over,
+            ..Hooks::default()
+        }
+    }
+
+    /// Callbacks may call back into the game, so the game must not be
+    /// borrowed while this runs.
+    pub fn update(&self, status: GameStatus) {
+        let last_score = self.last_score.replace(status.score);
+        let was_over = self.was_over.replace(status.over);
+        if !status.live {
+            return;
+        }
+
+        if status.score != last_score {
+            call(&self.on_score, status.score);
+        }
+        if status.over && !was_over {
+            call(&self.on_game_over, status.score);
+        }
+    }
+
+    pub fn reset(&self) {
+        self.last_score.set(0);
+        self.was_over.set(false);
+    }
+}
+
+fn call(callback: &Option<Function>, score: GameScore) {
+    if let Some(callback) = callback {
+        if let Err(error) = callback.call1(&JsValue::NULL, &JsValue::from(score)) {
+            web_sys::console::warn_2(&"Callback failed:".into(), &error);
+        }
+    }
+}
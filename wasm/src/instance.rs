@@ -0,0 +1,315 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use spacewars_game::{Game, GameMode, GameScore};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget, HtmlCanvasElement, KeyboardEvent, PointerEvent};
+
+use crate::font::font_ready;
+use crate::hooks::Hooks;
+use crate::options::Options;
+use crate::presenter::Presenter;
+use crate::storage::{load_bindings, LocalStorage};
+use crate::{canvas_position, context_2d, fit_canvas, now, window, FPS_INTERVAL};
+
+const KEYBOARD_EVENT: &str = "keydown";
+const RESIZE_EVENT: &str = "resize";
+const POINTER_DOWN_EVENT: &str = "pointerdown";
+const POINTER_MOVE_EVENT: &str = "pointermove";
+const POINTER_UP_EVENT: &str = "pointerup";
+const POINTER_CANCEL_EVENT: &str = "pointercancel";
+const TOUCH_POINTER: &str = "touch";
+const TAB_INDEX: &str = "tabindex";
+
+/// A game running on a canvas, controlled from the page:
+///
+/// ```js
+/// const game = new SpaceWars(canvas, { onGameOver: score => ... });
+/// game.start();
+/// ```
+///
+/// Each instance has its own canvas, listeners and game loop, so a page can
+/// run several of them.
+#[wasm_bindgen]
+pub struct SpaceWars {
+    instance: Rc<Instance>,
+}
+
+#[wasm_bindgen]
+impl SpaceWars {
+    #[wasm_bindgen(constructor)]
+    pub fn new(canvas: HtmlCanvasElement, options: JsValue) -> Result<SpaceWars, JsValue> {
+        let options = Options::from_js(&options, &window())
+            .map_err(|error| js_sys::Error::new(&error.to_string()))?;
+        let context = context_2d(&canvas)?;
+
+        let canvas_size = if options.fit_window {
+            fit_canvas(&canvas)
+        } else {
+            if let Some((width, height)) = options.size {
+                canvas.set_width(width as u32);
+                canvas.set_height(height as u32);
+            }
+            (f64::from(canvas.width()), f64::from(canvas.height()))
+        };
+
+        let presenter = Presenter::new(
+            new_game(&options),
+            load_bindings(&window(), &options.storage_key),
+            context,
+            canvas_size,
+            &options.storage_key,
+        );
+        let instance = Rc::new(Instance {
+            hooks: Hooks::new(options.on_score.clone(), options.on_game_over.clone()),
+            canvas,
+            presenter,
+            options,
+            listeners: RefCell::new(Vec::new()),
+            frame_callback: RefCell::new(None),
+            animation_frame: Cell::new(None),
+            destroyed: Cell::new(false),
+        });
+        instance.listen();
+
+        Ok(SpaceWars { instance })
+    }
+
+    /// Starts the game loop, showing the title screen.
+    pub fn start(&self) {
+        self.instance.run();
+    }
+
+    /// Freezes the game loop until `resume()`.
+    pub fn pause(&self) {
+        self.instance.stop();
+    }
+
+    pub fn resume(&self) {
+        self.instance.run();
+    }
+
+    /// Starts over from the title screen, with the same options.
+    pub fn reset(&self) {
+        self.instance
+            .presenter
+            .reset(new_game(&self.instance.options));
+        self.instance.hooks.reset();
+    }
+
+    /// Stops the game and removes its listeners. The instance cannot be
+    /// started again.
+    pub fn destroy(&self) {
+        self.instance.destroy();
+    }
+
+    /// The score of the current game, or of the last one played.
+    pub fn score(&self) -> GameScore {
+        self.instance.presenter.status().score
+    }
+}
+
+fn new_game(options: &Options) -> Game {
+    let storage = LocalStorage::new(&window(), &options.storage_key);
+    let mut game = Game::with_config(options.config, Box::new(storage))
+        .expect("Configuration is validated when loaded");
+    if let Some(campaign) = &options.campaign {
+        for mode in [GameMode::Solo, GameMode::Coop] {
+            game.set_campaign(mode, campaign.clone())
+                .expect("Campaign is validated when loaded");
+        }
+    }
+    game
+}
+
+/// An event listener, removed when the instance is destroyed.
+struct Listener {
+    target: EventTarget,
+    event_type: &'static str,
+    callback: Closure<dyn FnMut(Event)>,
+}
+
+struct Instance {
+    canvas: HtmlCanvasElement,
+    presenter: Presenter,
+    hooks: Hooks,
+    options: Options,
+    listeners: RefCell<Vec<Listener>>,
+    frame_callback: RefCell<Option<Closure<dyn FnMut()>>>,
+    animation_frame: Cell<Option<i32>>,
+    destroyed: Cell<bool>,
+}
+
+impl Instance {
+    fn listen(&self) {
+        let presenter = &self.presenter;
+        let canvas = &self.canvas;
+
+        // Without global keys, the canvas takes the keys once focused, by a
+        // click, a touch or the tab key.
+        let keyboard_target: EventTarget = if self.options.global_keys {
+            window().into()
+        } else {
+            if !canvas.has_attribute(TAB_INDEX) {
+                let _ = canvas.set_attribute(TAB_INDEX, "0");
+            }
+            canvas.clone().into()
+        };
+        self.add_listener(
+            keyboard_target,
+            KEYBOARD_EVENT,
+            enclose!( (presenter) move |event: Event| {
+                let event: KeyboardEvent = event.unchecked_into();
+                if presenter.is_bound(&event.key()) {
+                    event.prevent_default();
+                }
+                presenter.key_pressed(event.key(), event.repeat());
+            }),
+        );
+
+        if self.options.fit_window {
+            self.add_listener(
+                window().into(),
+                RESIZE_EVENT,
+                enclose!( (presenter, canvas) move |_: Event| {
+                    let (width, height) = fit_canvas(&canvas);
+                    presenter.resize(width, height);
+                }),
+            );
+        }
+
+        // Keeps the browser from scrolling or zooming while the game is played by touch.
+        let _ = canvas.style().set_property("touch-action", "none");
+
+        self.add_listener(
+            canvas.clone().into(),
+            POINTER_DOWN_EVENT,
+            enclose!( (presenter, canvas) move |event: Event| {
+                let event: PointerEvent = event.unchecked_into();
+                event.prevent_default();
+                let _ = canvas.focus();
+                let _ = canvas.set_pointer_capture(event.pointer_id());
+                let (x, y) = canvas_position(&canvas, &event);
+                let touch = event.pointer_type() == TOUCH_POINTER;
+                presenter.pointer_down(event.pointer_id(), x, y, now(), touch);
+            }),
+        );
+        self.add_listener(
+            canvas.clone().into(),
+            POINTER_MOVE_EVENT,
+            enclose!( (presenter, canvas) move |event: Event| {
+                let event: PointerEvent = event.unchecked_into();
+                let (x, y) = canvas_position(&canvas, &event);
+                presenter.pointer_moved(event.pointer_id(), x, y);
+            }),
+        );
+        self.add_listener(
+            canvas.clone().into(),
+            POINTER_UP_EVENT,
+            enclose!( (presenter) move |event: Event| {
+                let event: PointerEvent = event.unchecked_into();
+                presenter.pointer_up(event.pointer_id(), now());
+            }),
+        );
+        self.add_listener(
+            canvas.clone().into(),
+            POINTER_CANCEL_EVENT,
+            enclose!( (presenter) move |event: Event| {
+                let event: PointerEvent = event.unchecked_into();
+                presenter.pointer_cancelled(event.pointer_id());
+            }),
+        );
+    }
+
+    fn add_listener(
+        &self,
+        target: EventTarget,
+        event_type: &'static str,
+        handler: impl FnMut(Event) + 'static,
+    ) {
+        let callback = Closure::wrap(Box::new(handler) as Box<dyn FnMut(Event)>);
+        target
+            .add_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref())
+            .expect("should register event listeners OK");
+
+        self.listeners.borrow_mut().push(Listener {
+            target,
+            event_type,
+            callback,
+        });
+    }
+
+    /// The game loop holds the instance weakly, so that dropping the
+    /// instance stops it.
+    fn run(self: &Rc<Self>) {
+        if self.destroyed.get() || self.frame_callback.borrow().is_some() {
+            return;
+        }
+
+        let instance: Weak<Instance> = Rc::downgrade(self);
+        let last_frame = Cell::new(now());
+        let callback = Closure::wrap(Box::new(move || {
+            if let Some(instance) = instance.upgrade() {
+                instance.frame(&last_frame);
+            }
+        }) as Box<dyn FnMut()>);
+
+        self.frame_callback.replace(Some(callback));
+        self.request_frame();
+    }
+
+    fn frame(&self, last_frame: &Cell<f64>) {
+        self.animation_frame.set(None);
+
+        // The first frame waits for the game font, so that texts never show
+        // in the fallback font.
+        if !font_ready(&window()) {
+            self.request_frame();
+            return;
+        }
+
+        let curr_frame = now();
+        if curr_frame - last_frame.get() >= FPS_INTERVAL {
+            last_frame.set(curr_frame);
+            self.presenter.render();
+            self.hooks.update(self.presenter.status());
+        }
+
+        self.request_frame();
+    }
+
+    fn request_frame(&self) {
+        if let Some(callback) = self.frame_callback.borrow().as_ref() {
+            let id = window()
+                .request_animation_frame(callback.as_ref().unchecked_ref())
+                .expect("should register `requestAnimationFrame` OK");
+            self.animation_frame.set(Some(id));
+        }
+    }
+
+    fn stop(&self) {
+        if let Some(id) = self.animation_frame.take() {
+            let _ = window().cancel_animation_frame(id);
+        }
+        self.frame_callback.replace(None);
+    }
+
+    fn destroy(&self) {
+        self.destroyed.set(true);
+        self.stop();
+
+        for listener in self.listeners.borrow_mut().drain(..) {
+            let _ = listener.target.remove_event_listener_with_callback(
+                listener.event_type,
+                listener.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+impl Drop for Instance {
+    fn drop(&mut self) {
+        self.destroy();
+    }
+}
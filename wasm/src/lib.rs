@@ -1,22 +1,9 @@
-
 use wasm_bindgen::prelude::*;
 
-use crate::presenter::Presenter;
-use spacewars_game::Game;
 use wasm_bindgen::JsCast;
-use web_sys::KeyboardEvent;
+use web_sys::{HtmlCanvasElement, PointerEvent};
 
-mod graphics;
-mod presenter;
-
-const WINDOW_WIDTH: f64 = 800.0;
-const WINDOW_HEIGHT: f64 = 600.0;
-const FRAMES_PER_SECOND: f64 = 40.0;
-const ONE_SECOND: f64 = 1000.0;
-const FPS_INTERVAL: f64 = ONE_SECOND / FRAMES_PER_SECOND;
-const KEYBOARD_EVENT: &str = "keydown";
-
-use std::panic;
+pub use crate::instance::SpaceWars;
 
 // https://github.com/koute/stdweb/blob/master/examples/todomvc/src/main.rs#L31-L39
 macro_rules! enclose {
@@ -28,77 +15,72 @@ macro_rules! enclose {
     };
 }
 
+mod config;
+mod font;
+mod graphics;
+mod hooks;
+mod instance;
+mod options;
+mod pointer;
+mod presenter;
+mod screenshot;
+mod storage;
+
+const WINDOW_WIDTH: f64 = 800.0;
+const WINDOW_HEIGHT: f64 = 600.0;
+const FRAMES_PER_SECOND: f64 = 40.0;
+const ONE_SECOND: f64 = 1000.0;
+const FPS_INTERVAL: f64 = ONE_SECOND / FRAMES_PER_SECOND;
+
+use std::panic;
+
 fn window() -> web_sys::Window {
     web_sys::window().expect("no global `window` exists")
 }
 
-fn request_animation_frame(f: &Closure<dyn FnMut()>) {
-    window()
-        .request_animation_frame(f.as_ref().unchecked_ref())
-        .expect("should register `requestAnimationFrame` OK");
+fn window_size() -> (f64, f64) {
+    let width = window().inner_width().ok().and_then(|width| width.as_f64());
+    let height = window().inner_height().ok().and_then(|height| height.as_f64());
+
+    (
+        width.unwrap_or(WINDOW_WIDTH),
+        height.unwrap_or(WINDOW_HEIGHT),
+    )
 }
 
-fn add_event_listener_with_callback(event_type: &str, f: &Closure<dyn FnMut(KeyboardEvent)>) {
-    window()
-        .add_event_listener_with_callback(event_type, f.as_ref().unchecked_ref())
-        .expect("should register `requestAnimationFrame` OK");
+fn fit_canvas(canvas: &HtmlCanvasElement) -> (f64, f64) {
+    let (width, height) = window_size();
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
+
+    (width, height)
 }
 
 fn now() -> f64 {
     js_sys::Date::now()
 }
 
-fn keyborad_handling(presenter: &Presenter) {
-    let keyboard_handler = Closure::wrap(Box::new(
-        enclose!( (presenter) move |event: web_sys::KeyboardEvent| {
-            presenter.last_key(event.key());
-        }),
-    ) as Box<dyn FnMut(web_sys::KeyboardEvent)>);
-
-    add_event_listener_with_callback(KEYBOARD_EVENT, &keyboard_handler);
+/// Maps a pointer event to canvas pixels, which differ from CSS pixels when
+/// the canvas is styled to another size.
+fn canvas_position(canvas: &HtmlCanvasElement, event: &PointerEvent) -> (f64, f64) {
+    let scale_x = f64::from(canvas.width()) / f64::from(canvas.client_width().max(1));
+    let scale_y = f64::from(canvas.height()) / f64::from(canvas.client_height().max(1));
 
-    keyboard_handler.forget();
+    (
+        f64::from(event.offset_x()) * scale_x,
+        f64::from(event.offset_y()) * scale_y,
+    )
 }
 
-fn game_loop(presenter: &Presenter, last_frame: f64) {
-    let mut next_frame_time = last_frame;
-    let curr_frame = now();
-    if curr_frame - last_frame >= FPS_INTERVAL {
-        next_frame_time = curr_frame;
-        presenter.render();
-    }
-
-    let callack = Closure::wrap(Box::new(enclose!((presenter) move || {
-            game_loop(&presenter, next_frame_time);
-    })) as Box<dyn FnMut()>);
-
-    request_animation_frame(&callack);
-
-    callack.forget();
+fn context_2d(canvas: &HtmlCanvasElement) -> Result<web_sys::CanvasRenderingContext2d, JsValue> {
+    canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("the canvas has no 2d context"))?
+        .dyn_into::<web_sys::CanvasRenderingContext2d>()
+        .map_err(|_| JsValue::from_str("the canvas has no 2d context"))
 }
 
 #[wasm_bindgen(start)]
-pub fn start() {
+pub fn init() {
     panic::set_hook(Box::new(console_error_panic_hook::hook));
-    
-    let document = web_sys::window().unwrap().document().unwrap();
-    let canvas = document.get_element_by_id("canvas").unwrap();
-    let canvas: web_sys::HtmlCanvasElement = canvas
-        .dyn_into::<web_sys::HtmlCanvasElement>()
-        .map_err(|_| ())
-        .unwrap();
-
-    let context = canvas
-        .get_context("2d")
-        .unwrap()
-        .unwrap()
-        .dyn_into::<web_sys::CanvasRenderingContext2d>()
-        .unwrap();
-
-    let game = Game::new(WINDOW_WIDTH, WINDOW_HEIGHT);
-    let presenter = Presenter::new(game, context);
-
-    keyborad_handling(&presenter);
-
-    game_loop(&presenter, now());
 }
@@ -0,0 +1,70 @@
+use crate::config::{load_campaign, load_config, read_campaign, read_config};
+use crate::storage::DEFAULT_STORAGE_KEY;
+use js_sys::{Function, Reflect};
+use spacewars_game::{Campaign, ConfigError, GameConfig, ScreenSize};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Window;
+
+const WIDTH_OPTION: &str = "width";
+const HEIGHT_OPTION: &str = "height";
+const FIT_WINDOW_OPTION: &str = "fitWindow";
+const GLOBAL_KEYS_OPTION: &str = "globalKeys";
+const STORAGE_KEY_OPTION: &str = "storageKey";
+const CONFIG_OPTION: &str = "config";
+const CAMPAIGN_OPTION: &str = "campaign";
+const ON_SCORE_OPTION: &str = "onScore";
+const ON_GAME_OVER_OPTION: &str = "onGameOver";
+
+/// The options object passed to `new SpaceWars(canvas, options)`. Every
+/// field is optional.
+pub struct Options {
+    /// Canvas size in pixels, the canvas attributes when not set.
+    pub size: Option<ScreenSize>,
+    /// Fills the browser window and follows its size.
+    pub fit_window: bool,
+    /// Listens to the keys of the whole page instead of the focused canvas.
+    pub global_keys: bool,
+    /// Prefix of the local storage keys, to keep instances apart.
+    pub storage_key: String,
+    /// Falls back to `window.spacewarsConfig`.
+    pub config: GameConfig,
+    /// Falls back to `window.spacewarsCampaign`.
+    pub campaign: Option<Campaign>,
+    pub on_score: Option<Function>,
+    pub on_game_over: Option<Function>,
+}
+
+impl Options {
+    /// Invalid options are errors, unlike the window globals, which are
+    /// reported and ignored.
+    pub fn from_js(options: &JsValue, window: &Window) -> Result<Options, ConfigError> {
+        let width = get(options, WIDTH_OPTION).as_f64();
+        let height = get(options, HEIGHT_OPTION).as_f64();
+
+        let config = match read_config(options, CONFIG_OPTION)? {
+            Some(config) => config,
+            None => load_config(window),
+        };
+        let campaign = match read_campaign(options, CAMPAIGN_OPTION)? {
+            Some(campaign) => Some(campaign),
+            None => load_campaign(window),
+        };
+
+        Ok(Options {
+            size: width.zip(height),
+            fit_window: get(options, FIT_WINDOW_OPTION).is_truthy(),
+            global_keys: get(options, GLOBAL_KEYS_OPTION).is_truthy(),
+            storage_key: get(options, STORAGE_KEY_OPTION)
+                .as_string()
+                .unwrap_or_else(|| String::from(DEFAULT_STORAGE_KEY)),
+            config,
+            campaign,
+            on_score: get(options, ON_SCORE_OPTION).dyn_into().ok(),
+            on_game_over: get(options, ON_GAME_OVER_OPTION).dyn_into().ok(),
+        })
+    }
+}
+
+fn get(options: &JsValue, property: &str) -> JsValue {
+    Reflect::get(options, &JsValue::from_str(property)).unwrap_or(JsValue::UNDEFINED)
+}
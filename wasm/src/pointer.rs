@@ -0,0 +1,400 @@
+use spacewars_game::{
+    Anchor, Direction, Game, GameMode, Layer, Position, Primitive, RenderFrame, ScreenSize, Vector,
+};
+
+/// Presses shorter and smaller than this are taps, which fire.
+const TAP_DURATION: f64 = 250.0;
+const TAP_DISTANCE: f64 = 12.0;
+/// The hero stops steering once it is this close to its target, or within
+/// half of the distance it covers in a tick, whichever is larger.
+const STEER_TOLERANCE: f64 = 12.0;
+/// Dragging this far up or down changes the initial by one letter.
+const LETTER_DRAG_DISTANCE: f64 = 30.0;
+
+const FIRE_BUTTON_MARGIN: f64 = 80.0;
+const DASH_BUTTON_MARGIN: f64 = 190.0;
+const BUTTON_RADIUS: f64 = 50.0;
+const BUTTON_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 0.2];
+const BUTTON_TEXT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 0.6];
+const BUTTON_FONT_SIZE: u32 = 16;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Button {
+    Fire,
+    Dash,
+}
+
+impl Button {
+    const ALL: [Button; 2] = [Button::Fire, Button::Dash];
+
+    fn label(&self) -> &'static str {
+        match *self {
+            Button::Fire => "FIRE",
+            Button::Dash => "DASH",
+        }
+    }
+
+    /// Buttons sit in the bottom right corner, within reach of a thumb.
+    fn center(&self, (width, height): ScreenSize) -> Position {
+        match *self {
+            Button::Fire => Vector::new(width - FIRE_BUTTON_MARGIN, height - FIRE_BUTTON_MARGIN),
+            Button::Dash => Vector::new(width - DASH_BUTTON_MARGIN, height - FIRE_BUTTON_MARGIN),
+        }
+    }
+
+    fn at(position: Position, screen_size: ScreenSize) -> Option<Button> {
+        Button::ALL
+            .into_iter()
+            .find(|button| (position - button.center(screen_size)).length() <= BUTTON_RADIUS)
+    }
+}
+
+/// A pointer held down: a mouse button, a pen or a finger.
+struct Press {
+    id: i32,
+    start: Position,
+    position: Position,
+    started_at: f64,
+    button: Option<Button>,
+    /// Where the hero was when the drag started, set on the next turn.
+    hero_start: Option<Position>,
+    /// Letter changes already sent for this drag on the initials entry.
+    letter_steps: i32,
+}
+
+impl Press {
+    /// Letters to move up, or down when negative, since the last call.
+    fn letter_changes(&mut self) -> i32 {
+        let steps = ((self.start.y - self.position.y) / LETTER_DRAG_DISTANCE) as i32;
+        let changes = steps - self.letter_steps;
+        self.letter_steps = steps;
+        changes
+    }
+}
+
+/// Turns pointer events, in game coordinates, into player one inputs: drag
+/// anywhere to steer the hero relative to where the drag started, tap to
+/// fire, or hold the on-screen buttons shown after the first touch. Out of
+/// the game, taps confirm and drags change the initials of a high score.
+#[derive(Default)]
+pub struct PointerControls {
+    presses: Vec<Press>,
+    taps: u32,
+    dashes: u32,
+    show_buttons: bool,
+}
+
+impl PointerControls {
+    pub fn down(&mut self, id: i32, position: Position, time: f64, touch: bool, game: &Game) {
+        self.show_buttons |= touch;
+        let button = if self.buttons_visible(game) {
+            Button::at(position, game.screen_size())
+        } else {
+            None
+        };
+        if button == Some(Button::Dash) {
+            self.dashes += 1;
+        }
+
+        self.presses.retain(|press| press.id != id);
+        self.presses.push(Press {
+            id,
+            start: position,
+            position,
+            started_at: time,
+            button,
+            hero_start: None,
+            letter_steps: 0,
+        });
+    }
+
+    pub fn moved(&mut self, id: i32, position: Position) {
+        if let Some(press) = self.presses.iter_mut().find(|press| press.id == id) {
+            press.position = position;
+        }
+    }
+
+    pub fn up(&mut self, id: i32, time: f64) {
+        let Some(index) = self.presses.iter().position(|press| press.id == id) else {
+            return;
+        };
+
+        let press = self.presses.remove(index);
+        let tap = time - press.started_at <= TAP_DURATION
+            && (press.position - press.start).length() <= TAP_DISTANCE;
+        if tap && press.button.is_none() {
+            self.taps += 1;
+        }
+    }
+
+    pub fn cancel(&mut self, id: i32) {
+        self.presses.retain(|press| press.id != id);
+    }
+
+    /// Sends the inputs gathered since the last turn to the game.
+    pub fn update(&mut self, game: &mut Game) {
+        for _ in 0..std::mem::take(&mut self.taps) {
+            if game.is_in_game() {
+                game.fire_player_attack(Game::PLAYER_ONE);
+            } else {
+                game.confirm();
+            }
+        }
+        for _ in 0..std::mem::take(&mut self.dashes) {
+            game.dash_player(Game::PLAYER_ONE);
+        }
+
+        if game.is_entering_initials() {
+            self.change_letters(game);
+            return;
+        }
+        if !game.is_in_game() {
+            return;
+        }
+
+        if self
+            .presses
+            .iter()
+            .any(|press| press.button == Some(Button::Fire))
+        {
+            game.fire_player_attack(Game::PLAYER_ONE);
+        }
+
+        let hero = game.hero_position(Game::PLAYER_ONE);
+        let steering = self.presses.iter_mut().find(|press| press.button.is_none());
+        if let (Some(press), Some(hero)) = (steering, hero) {
+            let hero_start = *press.hero_start.get_or_insert(hero);
+            let target = hero_start + (press.position - press.start);
+            steer(game, hero, target);
+        }
+    }
+
+    fn change_letters(&mut self, game: &mut Game) {
+        let Some(press) = self.presses.iter_mut().find(|press| press.button.is_none()) else {
+            return;
+        };
+
+        let steps = press.letter_changes();
+        let direction = if steps > 0 {
+            Direction::UP
+        } else {
+            Direction::DOWN
+        };
+        for _ in 0..steps.abs() {
+            game.move_player(Game::PLAYER_ONE, direction);
+        }
+    }
+
+    fn buttons_visible(&self, game: &Game) -> bool {
+        self.show_buttons && game.is_in_game()
+    }
+
+    pub fn draw_buttons(&self, frame: &mut RenderFrame, game: &Game) {
+        if !self.buttons_visible(game) {
+            return;
+        }
+
+        for button in Button::ALL {
+            let center = button.center(game.screen_size());
+            frame.push(
+                Layer::Hud,
+                Primitive::Circle {
+                    center,
+                    radius: BUTTON_RADIUS,
+                    color: BUTTON_COLOR,
+                },
+            );
+            frame.push(
+                Layer::Hud,
+                Primitive::Text {
+                    content: String::from(button.label()),
+                    position: center + Vector::new(0.0, f64::from(BUTTON_FONT_SIZE) / 2.0),
+                    font_size: BUTTON_FONT_SIZE,
+                    anchor: Anchor::Center,
+                    color: BUTTON_TEXT_COLOR,
+                },
+            );
+        }
+    }
+}
+
+fn steer(game: &mut Game, hero: Position, target: Position) {
+    let offset = target - hero;
+    let tolerance = steer_tolerance(game);
+
+    if offset.x > tolerance {
+        game.move_player(Game::PLAYER_ONE, Direction::RIGHT);
+    } else if offset.x < -tolerance {
+        game.move_player(Game::PLAYER_ONE, Direction::LEFT);
+    }
+
+    if offset.y > tolerance {
+        game.move_player(Game::PLAYER_ONE, Direction::DOWN);
+    } else if offset.y < -tolerance {
+        game.move_player(Game::PLAYER_ONE, Direction::UP);
+    }
+}
+
+/// A single thrust from rest moves the hero by its acceleration, capped at
+/// its max speed, so a target closer than half of that would be overshot.
+fn steer_tolerance(game: &Game) -> f64 {
+    let hero = &game.config().hero;
+    let tuning = match game.mode() {
+        GameMode::Versus => hero.versus_movement,
+        _ => hero.movement,
+    };
+    STEER_TOLERANCE.max(tuning.acceleration.min(tuning.max_speed) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use spacewars_game::MemoryStorage;
+
+    use super::*;
+
+    fn new_game() -> Game {
+        Game::new(800.0, 600.0, Box::new(MemoryStorage::default())).unwrap()
+    }
+
+    fn press_at(y: f64) -> Press {
+        Press {
+            id: 1,
+            start: Vector::new(100.0, 300.0),
+            position: Vector::new(100.0, y),
+            started_at: 0.0,
+            button: None,
+            hero_start: None,
+            letter_steps: 0,
+        }
+    }
+
+    #[test]
+    fn up_should_count_a_short_press_as_a_tap() {
+        // Arrange
+        let game = new_game();
+        let mut pointer = PointerControls::default();
+        pointer.down(1, Vector::new(100.0, 100.0), 0.0, false, &game);
+        pointer.moved(1, Vector::new(105.0, 100.0));
+
+        // Act
+        pointer.up(1, 100.0);
+
+        // Assert
+        assert_eq!(pointer.taps, 1);
+        assert!(pointer.presses.is_empty());
+    }
+
+    #[test]
+    fn up_should_not_count_a_drag_as_a_tap() {
+        // Arrange
+        let game = new_game();
+        let mut pointer = PointerControls::default();
+        pointer.down(1, Vector::new(100.0, 100.0), 0.0, false, &game);
+        pointer.moved(1, Vector::new(100.0 + TAP_DISTANCE * 2.0, 100.0));
+
+        // Act
+        pointer.up(1, 100.0);
+
+        // Assert
+        assert_eq!(pointer.taps, 0);
+    }
+
+    #[test]
+    fn up_should_not_count_a_long_press_as_a_tap() {
+        // Arrange
+        let game = new_game();
+        let mut pointer = PointerControls::default();
+        pointer.down(1, Vector::new(100.0, 100.0), 0.0, false, &game);
+
+        // Act
+        pointer.up(1, TAP_DURATION * 2.0);
+
+        // Assert
+        assert_eq!(pointer.taps, 0);
+    }
+
+    #[test]
+    fn down_should_press_the_dash_button_once_touched() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+        let mut pointer = PointerControls::default();
+        let dash = Button::Dash.center(game.screen_size());
+
+        // Act
+        pointer.down(1, dash, 0.0, true, &game);
+        pointer.up(1, 100.0);
+
+        // Assert
+        assert_eq!(pointer.dashes, 1);
+        assert_eq!(pointer.taps, 0);
+    }
+
+    #[test]
+    fn letter_changes_should_change_one_letter_per_drag_distance() {
+        // Arrange
+        let mut press = press_at(300.0 - LETTER_DRAG_DISTANCE * 2.5);
+
+        // Act
+        let up = press.letter_changes();
+        press.position.y = 300.0 + LETTER_DRAG_DISTANCE;
+        let down = press.letter_changes();
+        let still = press.letter_changes();
+
+        // Assert
+        assert_eq!(up, 2);
+        assert_eq!(down, -3);
+        assert_eq!(still, 0);
+    }
+
+    #[test]
+    fn update_should_steer_the_hero_along_the_drag() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+        let mut pointer = PointerControls::default();
+        pointer.down(1, Vector::new(100.0, 100.0), 0.0, false, &game);
+        pointer.update(&mut game);
+        let start = game.hero_position(Game::PLAYER_ONE).unwrap();
+
+        // Act
+        pointer.moved(1, Vector::new(40.0, 100.0));
+        for _ in 0..4 {
+            pointer.update(&mut game);
+            game.next_turn();
+        }
+
+        // Assert
+        let hero = game.hero_position(Game::PLAYER_ONE).unwrap();
+        assert_eq!(hero.x, start.x - 60.0);
+        assert_eq!(hero.y, start.y);
+    }
+
+    #[test]
+    fn update_should_settle_within_a_step_of_the_target() {
+        // Arrange
+        let mut game = new_game();
+        game.start(GameMode::Solo);
+        let mut pointer = PointerControls::default();
+        pointer.down(1, Vector::new(100.0, 100.0), 0.0, false, &game);
+        pointer.update(&mut game);
+        let start = game.hero_position(Game::PLAYER_ONE).unwrap();
+        pointer.moved(1, Vector::new(84.0, 100.0));
+        pointer.update(&mut game);
+        game.next_turn();
+        let moved = game.hero_position(Game::PLAYER_ONE).unwrap();
+
+        // Act
+        let positions: Vec<f64> = (0..4)
+            .map(|_| {
+                pointer.update(&mut game);
+                game.next_turn();
+                game.hero_position(Game::PLAYER_ONE).unwrap().x
+            })
+            .collect();
+
+        // Assert
+        assert_eq!(moved.x, start.x - 30.0);
+        assert_eq!(positions, vec![moved.x; 4]);
+    }
+}
@@ -1,61 +1,211 @@
-use crate::graphics::{draw_background, draw_polygon, draw_text};
-use spacewars_game::{Direction, Game};
+use crate::graphics::{draw_background, draw_frame, reset_transform, set_viewport_transform};
+use crate::hooks::GameStatus;
+use crate::pointer::PointerControls;
+use crate::screenshot::download_svg;
+use crate::storage::save_bindings;
+use spacewars_game::{frame_to_svg, Game, KeyBindings, Rebinding, ScreenSize, Vector, Viewport};
 use std::rc::Rc;
 use std::{borrow::Borrow, cell::RefCell};
 use web_sys::CanvasRenderingContext2d;
 
-const MOVE_RIGHT_KEY: &str = "ArrowRight";
-const MOVE_LEFT_KEY: &str = "ArrowLeft";
-const ATTACK_KEY: &str = " ";
+const REBIND_KEY: &str = "F2";
+const SCREENSHOT_KEY: &str = "F9";
 
 #[derive(Clone)]
 pub struct Presenter {
     game: Rc<RefCell<Game>>,
     context: Rc<CanvasRenderingContext2d>,
-    last_key: Rc<RefCell<Option<String>>>,
+    viewport: Rc<RefCell<Viewport>>,
+    pressed_keys: Rc<RefCell<Vec<String>>>,
+    bindings: Rc<RefCell<KeyBindings>>,
+    rebinding: Rc<RefCell<Option<Rebinding>>>,
+    pointer: Rc<RefCell<PointerControls>>,
+    storage_key: Rc<str>,
 }
 
 impl Presenter {
-    pub fn new(game: Game, context: CanvasRenderingContext2d) -> Presenter {
+    pub fn new(
+        game: Game,
+        bindings: KeyBindings,
+        context: CanvasRenderingContext2d,
+        canvas_size: ScreenSize,
+        storage_key: &str,
+    ) -> Presenter {
+        let viewport = Viewport::fit(game.screen_size(), canvas_size);
         Presenter {
             game: Rc::new(RefCell::new(game)),
             context: Rc::new(context),
-            last_key: Rc::new(RefCell::new(None)),
+            viewport: Rc::new(RefCell::new(viewport)),
+            pressed_keys: Rc::new(RefCell::new(Vec::new())),
+            bindings: Rc::new(RefCell::new(bindings)),
+            rebinding: Rc::new(RefCell::new(None)),
+            pointer: Rc::new(RefCell::new(PointerControls::default())),
+            storage_key: Rc::from(storage_key),
         }
     }
 
+    /// Replaces the game, dropping any input meant for the previous one.
+    pub fn reset(&self, game: Game) {
+        let canvas_size = RefCell::borrow(&self.viewport).physical_size();
+        RefCell::replace(
+            &self.viewport,
+            Viewport::fit(game.screen_size(), canvas_size),
+        );
+        RefCell::replace(&self.game, game);
+        RefCell::replace(&self.pressed_keys, Vec::new());
+        RefCell::replace(&self.rebinding, None);
+        RefCell::replace(&self.pointer, PointerControls::default());
+    }
+
+    pub fn status(&self) -> GameStatus {
+        GameStatus::of(&RefCell::borrow(&self.game))
+    }
+
     pub fn render(&self) {
         let mut game = self.game.borrow_mut();
         let context = self.context.borrow();
+        let viewport = *RefCell::borrow(&self.viewport);
 
         self.handle_event(&mut game);
 
-        game.next_turn();
+        let frame = match RefCell::borrow(&self.rebinding).as_ref() {
+            Some(rebinding) => rebinding.render_frame(game.screen_size()),
+            None => {
+                let mut pointer = self.pointer.borrow_mut();
+                pointer.update(&mut game);
+                game.next_turn();
+
+                let mut frame = game.render_frame();
+                pointer.draw_buttons(&mut frame, &game);
+                frame
+            }
+        };
+
+        reset_transform(context);
+        draw_background(context, viewport.physical_size(), frame.background());
+
+        set_viewport_transform(context, &viewport);
+        draw_frame(&frame, context);
 
-        draw_background(context, game.screen_size());
-        for object in game.space_objects().iter() {
-            draw_polygon(object, context);
+        reset_transform(context);
+        draw_frame(&viewport.bar_frame(), context);
+    }
+
+    pub fn resize(&self, width: f64, height: f64) {
+        let logical_size = RefCell::borrow(&self.game).screen_size();
+        RefCell::replace(&self.viewport, Viewport::fit(logical_size, (width, height)));
+    }
+
+    /// `repeat` is the `KeyboardEvent.repeat` flag of the browser.
+    pub fn key_pressed(&self, new_key: String, repeat: bool) {
+        let rebinding = RefCell::borrow(&self.rebinding).is_some();
+        if repeat && !KeyBindings::accepts_repeat(&key_name(&new_key), rebinding) {
+            return;
         }
+        self.pressed_keys.borrow_mut().push(new_key);
+    }
+
+    /// Whether the key controls the game, so that the page should not
+    /// scroll or otherwise react to it. Other keys, which only start a game
+    /// from the title screen, keep their browser shortcuts.
+    pub fn is_bound(&self, key: &str) -> bool {
+        let key = key_name(key);
+        matches!(key.as_str(), REBIND_KEY | SCREENSHOT_KEY)
+            || RefCell::borrow(&self.bindings).is_bound(&key)
+    }
 
-        for text in game.texts().iter() {
-            draw_text(text, context);
+    /// Presses outside of the game area, on the bars, are ignored.
+    pub fn pointer_down(&self, id: i32, x: f64, y: f64, time: f64, touch: bool) {
+        let viewport = *RefCell::borrow(&self.viewport);
+        if let Some(position) = viewport.to_logical(Vector::new(x, y)) {
+            let game = RefCell::borrow(&self.game);
+            self.pointer
+                .borrow_mut()
+                .down(id, position, time, touch, &game);
         }
     }
 
-    pub fn last_key(&self, new_key: String) {
-        RefCell::replace(&self.last_key, Some(new_key));
+    /// Drags keep steering when they leave the game area.
+    pub fn pointer_moved(&self, id: i32, x: f64, y: f64) {
+        let viewport = *RefCell::borrow(&self.viewport);
+        let position = (Vector::new(x, y) - viewport.offset()) * (1.0 / viewport.scale());
+        self.pointer.borrow_mut().moved(id, position);
+    }
+
+    pub fn pointer_up(&self, id: i32, time: f64) {
+        self.pointer.borrow_mut().up(id, time);
+    }
+
+    pub fn pointer_cancelled(&self, id: i32) {
+        self.pointer.borrow_mut().cancel(id);
     }
 
     fn handle_event(&self, game: &mut Game) {
-        if let Some(ref mut last_key) = *self.last_key.borrow_mut() {
-            match last_key.as_str() {
-                MOVE_RIGHT_KEY => game.move_player(Direction::RIGHT),
-                MOVE_LEFT_KEY => game.move_player(Direction::LEFT),
-                ATTACK_KEY => game.fire_player_attack(),
-                _ => game.key_pressed(),
+        let pressed_keys = RefCell::replace(&self.pressed_keys, Vec::new());
+
+        for key in pressed_keys {
+            let key = key_name(&key);
+            if self.rebind(&key) {
+                continue;
+            }
+
+            match key.as_str() {
+                REBIND_KEY => {
+                    let bindings = RefCell::borrow(&self.bindings).clone();
+                    RefCell::replace(&self.rebinding, Some(Rebinding::new(bindings)));
+                }
+                SCREENSHOT_KEY => {
+                    let window = web_sys::window().expect("no global `window` exists");
+                    if let Err(error) = download_svg(&window, &frame_to_svg(&game.render_frame())) {
+                        web_sys::console::warn_2(&"Could not save screenshot:".into(), &error);
+                    }
+                }
+                _ => {
+                    let player_count = game.mode().player_count();
+                    for input in RefCell::borrow(&self.bindings).inputs(&key, player_count) {
+                        input.apply(game);
+                    }
+                }
             }
         }
+    }
 
-        RefCell::replace(&self.last_key, None);
+    /// Sends the key to the rebinding screen when it is open, and saves the
+    /// bindings once every control is done.
+    fn rebind(&self, key: &str) -> bool {
+        let mut rebinding = self.rebinding.borrow_mut();
+        let Some(screen) = rebinding.as_mut() else {
+            return false;
+        };
+
+        screen.press(key);
+        if screen.is_cancelled() {
+            *rebinding = None;
+        } else if screen.is_done() {
+            let bindings = screen.bindings().clone();
+            let window = web_sys::window().expect("no global `window` exists");
+            save_bindings(&window, &self.storage_key, &bindings);
+            RefCell::replace(&self.bindings, bindings);
+            *rebinding = None;
+        }
+        true
+    }
+}
+
+/// Names browser keys as the shared key bindings do.
+fn key_name(key: &str) -> String {
+    match key {
+        "ArrowLeft" => String::from("Left"),
+        "ArrowRight" => String::from("Right"),
+        "ArrowUp" => String::from("Up"),
+        "ArrowDown" => String::from("Down"),
+        "Control" => String::from("Ctrl"),
+        _ => {
+            let mut characters = key.chars();
+            match (characters.next(), characters.next()) {
+                (Some(character), None) => KeyBindings::character_key(character),
+                _ => String::from(key),
+            }
+        }
     }
 }
@@ -0,0 +1,33 @@
+use js_sys::Array;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url, Window};
+
+const SVG_TYPE: &str = "image/svg+xml";
+/// Revoking the link right after the click can cancel the download.
+const REVOKE_DELAY_MILLIS: i32 = 1000;
+
+/// Offers the SVG document as a file download through a temporary link.
+pub fn download_svg(window: &Window, svg: &str) -> Result<(), JsValue> {
+    let document = window.document().ok_or("no document")?;
+
+    let parts = Array::of1(&JsValue::from_str(svg));
+    let blob =
+        Blob::new_with_str_sequence_and_options(&parts, BlobPropertyBag::new().type_(SVG_TYPE))?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let link: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    link.set_href(&url);
+    link.set_download(&format!("spacewars-{}.svg", js_sys::Date::now() as u64));
+    link.click();
+
+    let revoke = Closure::once_into_js(move || {
+        let _ = Url::revoke_object_url(&url);
+    });
+    window.set_timeout_with_callback_and_timeout_and_arguments_0(
+        revoke.unchecked_ref(),
+        REVOKE_DELAY_MILLIS,
+    )?;
+
+    Ok(())
+}
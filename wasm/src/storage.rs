@@ -0,0 +1,81 @@
+use spacewars_game::{ConfigError, HighScoreStorage, KeyBindings};
+use web_sys::{Storage, Window};
+
+/// Prefix of the local storage keys, unless an instance picks its own.
+pub const DEFAULT_STORAGE_KEY: &str = "spacewars";
+const HIGH_SCORE_KEY: &str = "high_scores";
+const BINDINGS_KEY: &str = "bindings";
+
+fn item_key(storage_key: &str, key: &str) -> String {
+    format!("{}.{}", storage_key, key)
+}
+
+pub struct LocalStorage {
+    storage: Option<Storage>,
+    key: String,
+}
+
+impl LocalStorage {
+    pub fn new(window: &Window, storage_key: &str) -> LocalStorage {
+        LocalStorage {
+            storage: window.local_storage().ok().flatten(),
+            key: item_key(storage_key, HIGH_SCORE_KEY),
+        }
+    }
+}
+
+impl HighScoreStorage for LocalStorage {
+    fn load(&self) -> Option<String> {
+        self.storage.as_ref()?.get_item(&self.key).ok().flatten()
+    }
+
+    fn save(&mut self, content: &str) {
+        if let Some(storage) = &self.storage {
+            if storage.set_item(&self.key, content).is_err() {
+                web_sys::console::warn_1(&"Could not save high scores".into());
+            }
+        }
+    }
+}
+
+pub fn load_bindings(window: &Window, storage_key: &str) -> KeyBindings {
+    let saved = window.local_storage().ok().flatten().and_then(|storage| {
+        storage
+            .get_item(&item_key(storage_key, BINDINGS_KEY))
+            .ok()
+            .flatten()
+    });
+
+    match saved.map(|json| parse_bindings(&json)) {
+        Some(Ok(bindings)) => bindings,
+        Some(Err(error)) => {
+            let message = format!("Ignoring saved key bindings: {}", error);
+            web_sys::console::warn_1(&message.into());
+            KeyBindings::default()
+        }
+        None => KeyBindings::default(),
+    }
+}
+
+fn parse_bindings(json: &str) -> Result<KeyBindings, ConfigError> {
+    let bindings: KeyBindings =
+        serde_json::from_str(json).map_err(|error| ConfigError::Parse(error.to_string()))?;
+    bindings.validate()?;
+    Ok(bindings)
+}
+
+pub fn save_bindings(window: &Window, storage_key: &str, bindings: &KeyBindings) {
+    let saved = window
+        .local_storage()
+        .ok()
+        .flatten()
+        .zip(serde_json::to_string(bindings).ok())
+        .is_some_and(|(storage, json)| {
+            storage
+                .set_item(&item_key(storage_key, BINDINGS_KEY), &json)
+                .is_ok()
+        });
+    if !saved {
+        web_sys::console::warn_1(&"Could not save key bindings".into());
+    }
+}